@@ -1,69 +1,39 @@
 use serde::{Deserialize, Serialize};
 use anyhow::{Context, Result};
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
+use pulldown_cmark::{html, Event, LinkType as CmarkLinkType, Parser, Tag, TagEnd};
 use regex::Regex;
-use yaml_front_matter::{Document, YamlFrontMatter};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use walkdir::WalkDir;
+use crate::assets::{find_asset_issues, real_path, AssetIssue, AssetIssueKind};
+use crate::external_links::{BrokenExternalLink, ExternalLinkOptions};
 
-/// Article metadata structure with default values
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ArticleMetadata {
-    pub title: String,
-    #[serde(default)]
-    pub home_display: bool,
-    pub category: Option<String>,
-    #[serde(default = "default_importance")]
-    pub importance: u8,
-    #[serde(default)]
-    pub related_articles: Vec<String>,
-    #[serde(default)]
-    pub tags: Vec<String>,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
-    pub author_image: Option<String>,
-}
-
-impl Default for ArticleMetadata {
-    fn default() -> Self {
-        Self {
-            title: "Untitled".to_string(),
-            home_display: false,
-            category: None,
-            importance: default_importance(),
-            related_articles: Vec::new(),
-            tags: Vec::new(),
-            created_at: None,
-            updated_at: None,
-            author_image: None,
-        }
-    }
-}
-
-fn default_importance() -> u8 {
-    3
-}
-
-/// Types of links that can be extracted from markdown content
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum LinkType {
-    WikiLink,      // [[article-name]] format
-    MarkdownLink,  // [text](slug) format
-}
-
-/// Represents a link found in markdown content
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExtractedLink {
-    pub target_slug: String,
-    pub link_type: LinkType,
-    pub context: String,
-    pub position: usize,
-    pub original_text: String,
-}
+pub use crate::schema::{
+    article_content_path, ArticleContentData, AssetReference, AssetVariant, ArticleIndex, ArticleMetadata,
+    ArticlesData, ConnectionType, DateSource, DateSources, ExternalLink, ExtractedLink, GraphConnection, GraphNode, LightweightArticle,
+    LinkGraphData, LinkType, ProcessedArticle, PublishedAsset, ARTICLE_CONTENT_DIR, ASSET_DIR, ASSET_URL_PREFIX,
+    SCHEMA_VERSION,
+};
 
-/// Link extractor for markdown content
+/// Link extractor for markdown content.
+///
+/// Walks the `pulldown_cmark` event stream, so only links in prose are
+/// collected: code blocks, inline code and raw HTML are skipped, and
+/// images are reported separately as assets.
 pub struct LinkExtractor {
     wiki_regex: Regex,
-    markdown_regex: Regex,
+}
+
+/// Links and image assets found in a markdown document
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarkdownReferences {
+    pub links: Vec<ExtractedLink>,
+    pub assets: Vec<AssetReference>,
+    pub external_links: Vec<ExternalLink>,
 }
 
 impl LinkExtractor {
@@ -71,74 +41,148 @@ impl LinkExtractor {
     pub fn new() -> Result<Self> {
         let wiki_regex = Regex::new(r"\[\[([^\]]+)\]\]")
             .context("Failed to compile wiki link regex")?;
-        let markdown_regex = Regex::new(r"\[([^\]]+)\]\(([^)]+)\)")
-            .context("Failed to compile markdown link regex")?;
         
-        Ok(Self {
-            wiki_regex,
-            markdown_regex,
-        })
+        Ok(Self { wiki_regex })
     }
 
     /// Extract all links from markdown content
     pub fn extract_links(&self, content: &str) -> Vec<ExtractedLink> {
-        let mut links = Vec::new();
+        self.extract(content).links
+    }
+
+    /// Extract links and image assets from markdown content
+    pub fn extract(&self, content: &str) -> MarkdownReferences {
+        let mut references = MarkdownReferences::default();
+        // Source range of consecutive prose text events; pulldown_cmark
+        // splits text around brackets, so [[wiki links]] span several events
+        let mut text_run: Option<Range<usize>> = None;
+        let mut in_code_block = false;
+        // Markdown link or image currently open: (start offset, destination, text)
+        let mut open_link: Option<(usize, String, String)> = None;
+        let mut open_autolink: Option<(usize, String)> = None;
+        let mut open_image: Option<(usize, String, String)> = None;
+
+        for (event, range) in Parser::new(content).into_offset_iter() {
+            if let Event::Text(text) = &event {
+                if let Some((_, _, alt_text)) = open_image.as_mut() {
+                    alt_text.push_str(text);
+                    continue;
+                }
+                if let Some((_, _, label)) = open_link.as_mut() {
+                    label.push_str(text);
+                }
+                if !in_code_block {
+                    text_run = Some(match text_run.take() {
+                        Some(run) => run.start..range.end,
+                        None => range,
+                    });
+                    continue;
+                }
+            }
+            if let Some(run) = text_run.take() {
+                self.extract_wiki_links(content, run, &mut references.links);
+            }
+
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+                Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                Event::Code(code) => {
+                    if let Some((_, _, label)) = open_link.as_mut() {
+                        label.push_str(&code);
+                    }
+                }
+                Event::Start(Tag::Link { link_type, dest_url, .. }) => {
+                    if matches!(link_type, CmarkLinkType::Autolink | CmarkLinkType::Email) {
+                        open_autolink = Some((range.start, dest_url.to_string()));
+                    } else {
+                        open_link = Some((range.start, dest_url.to_string(), String::new()));
+                    }
+                }
+                Event::End(TagEnd::Link) => {
+                    if let Some((start, dest_url)) = open_autolink.take() {
+                        push_external_link(content, start, &dest_url, &mut references.external_links);
+                    } else if let Some((start, dest_url, text)) = open_link.take() {
+                        push_external_link(content, start, &dest_url, &mut references.external_links);
+                        self.push_markdown_link(content, start..range.end, &dest_url, text, &mut references.links);
+                    }
+                }
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    open_image = Some((range.start, dest_url.to_string(), String::new()));
+                }
+                Event::End(TagEnd::Image) => {
+                    if let Some((start, path, alt_text)) = open_image.take() {
+                        let (line, column) = line_column(content, start);
+                        references.assets.push(AssetReference {
+                            path,
+                            alt_text,
+                            position: start,
+                            original_text: content[start..range.end].to_string(),
+                            line,
+                            column,
+                            published: None, // Filled in by AssetPublisher
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(run) = text_run.take() {
+            self.extract_wiki_links(content, run, &mut references.links);
+        }
+        
+        // Sort links by position for consistent ordering
+        references.links.sort_by_key(|link| link.position);
         
-        // Extract wiki-style links [[article-name]]
-        for cap in self.wiki_regex.captures_iter(content) {
+        references
+    }
+
+    /// Extract wiki-style links [[article-name#section|label]] from a run of prose
+    fn extract_wiki_links(&self, content: &str, run: Range<usize>, links: &mut Vec<ExtractedLink>) {
+        for cap in self.wiki_regex.captures_iter(&content[run.clone()]) {
             let full_match = cap.get(0).unwrap();
-            let target = cap.get(1).unwrap().as_str();
-            let position = full_match.start();
+            let wiki_link = WikiLink::parse(cap.get(1).unwrap().as_str());
+            let position = run.start + full_match.start();
+            let (line, column) = line_column(content, position);
             
             links.push(ExtractedLink {
-                target_slug: self.generate_slug_from_title(target),
+                target_slug: self.generate_slug_from_title(wiki_link.target),
                 link_type: LinkType::WikiLink,
                 context: self.get_context(content, position, 100),
                 position,
                 original_text: full_match.as_str().to_string(),
+                section: wiki_link.section.map(str::to_string),
+                label: wiki_link.label.map(str::to_string),
+                line,
+                column,
             });
         }
-        
-        // Extract markdown-style links [text](slug)
-        for cap in self.markdown_regex.captures_iter(content) {
-            let full_match = cap.get(0).unwrap();
-            let _text = cap.get(1).unwrap().as_str();
-            let target = cap.get(2).unwrap().as_str();
-            let position = full_match.start();
-            
-            // Only process internal links (not starting with http/https)
-            if !target.starts_with("http") && !target.starts_with("mailto:") {
-                links.push(ExtractedLink {
-                    target_slug: target.to_string(),
-                    link_type: LinkType::MarkdownLink,
-                    context: self.get_context(content, position, 100),
-                    position,
-                    original_text: full_match.as_str().to_string(),
-                });
-            }
+    }
+
+    /// Record a markdown-style link [text](slug#section) if it points to another article
+    fn push_markdown_link(&self, content: &str, range: Range<usize>, dest_url: &str, text: String, links: &mut Vec<ExtractedLink>) {
+        let (target, section) = split_section(dest_url);
+        
+        // Only process links to articles, which also skips same-page
+        // #fragment links
+        if let Some(slug) = article_link_slug(target) {
+            let (line, column) = line_column(content, range.start);
+            links.push(ExtractedLink {
+                target_slug: slug.to_string(),
+                link_type: LinkType::MarkdownLink,
+                context: self.get_context(content, range.start, 100),
+                position: range.start,
+                original_text: content[range].to_string(),
+                section: section.map(str::to_string),
+                label: Some(text),
+                line,
+                column,
+            });
         }
-        
-        // Sort links by position for consistent ordering
-        links.sort_by_key(|link| link.position);
-        
-        links
     }
 
     /// Generate a slug from article title (for wiki links)
-    fn generate_slug_from_title(&self, title: &str) -> String {
-        let slug = title
-            .to_lowercase()
-            .trim()
-            .replace(' ', "-")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
-            .collect::<String>()
-            .trim_matches('-')
-            .to_string();
-        
-        // Replace multiple consecutive dashes with single dash
-        let re = Regex::new(r"-+").unwrap();
-        re.replace_all(&slug, "-").to_string()
+    pub fn generate_slug_from_title(&self, title: &str) -> String {
+        slugify(title)
     }
 
     /// Get context around a link position
@@ -187,12 +231,10 @@ impl LinkExtractor {
             0
         } else {
             // Search forward for word boundary
-            for i in position..chars.len() {
-                if chars[i].is_whitespace() || chars[i] == '\n' {
-                    return i;
-                }
-            }
-            chars.len()
+            chars[position..]
+                .iter()
+                .position(|c| c.is_whitespace() || *c == '\n')
+                .map_or(chars.len(), |offset| position + offset)
         }
     }
 }
@@ -203,15 +245,92 @@ impl Default for LinkExtractor {
     }
 }
 
+/// 1-based line and character column of a byte offset in `content`
+pub fn line_column(content: &str, position: usize) -> (usize, usize) {
+    let before = &content[..position];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// The parts of a `[[target#section|label]]` wiki link
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiLink<'a> {
+    pub target: &'a str,
+    pub section: Option<&'a str>,
+    pub label: Option<&'a str>,
+}
+
+impl<'a> WikiLink<'a> {
+    /// Split the text between `[[` and `]]` into target, section and label
+    pub fn parse(inner: &'a str) -> Self {
+        let (target, label) = match inner.split_once('|') {
+            Some((target, label)) => (target, Some(label.trim())),
+            None => (inner, None),
+        };
+        let (target, section) = split_section(target);
+        Self {
+            target: target.trim(),
+            section: section.map(str::trim).filter(|s| !s.is_empty()),
+            label: label.filter(|l| !l.is_empty()),
+        }
+    }
+}
+
+/// Split a link target into the article part and an optional `#section`
+pub fn split_section(target: &str) -> (&str, Option<&str>) {
+    match target.split_once('#') {
+        Some((target, section)) => (target, (!section.is_empty()).then_some(section)),
+        None => (target, None),
+    }
+}
+
+/// HTML id of a heading, as referenced by `#section` links
+pub fn heading_anchor(text: &str) -> String {
+    slugify(text)
+}
+
+/// Ids of all headings in `markdown`, in document order. Repeated headings
+/// get a `-1`, `-2`, ... suffix so every id is unique.
+pub fn heading_anchors(markdown: &str) -> Vec<String> {
+    let mut anchors = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut heading_text: Option<String> = None;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading_text = Some(String::new()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = heading_text.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                let anchor = heading_anchor(&heading_text.take().unwrap_or_default());
+                let count = seen.entry(anchor.clone()).or_insert(0);
+                anchors.push(if *count == 0 { anchor } else { format!("{}-{}", anchor, count) });
+                *count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    anchors
+}
+
 /// Validation error types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ValidationErrorType {
     BrokenLink,
+    BrokenSectionLink,
     InvalidRelatedArticle,
     MissingMetadata,
     InvalidMetadata,
     CircularReference,
     OrphanedArticle,
+    BrokenExternalLink,
+    MissingAsset,
+    AssetOutsideArticles,
 }
 
 /// Validation warning types
@@ -222,16 +341,104 @@ pub enum ValidationWarningType {
     HighImportanceWithFewLinks,
     MissingBacklinks,
     InconsistentCasing,
+    LinkToDraft,
+}
+
+impl ValidationErrorType {
+    /// Identifier used in the project config and `validation_ignore`
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            ValidationErrorType::BrokenLink => "broken-link",
+            ValidationErrorType::BrokenSectionLink => "broken-section-link",
+            ValidationErrorType::InvalidRelatedArticle => "invalid-related-article",
+            ValidationErrorType::MissingMetadata => "missing-metadata",
+            ValidationErrorType::InvalidMetadata => "invalid-metadata",
+            ValidationErrorType::CircularReference => "circular-reference",
+            ValidationErrorType::OrphanedArticle => "orphaned-article",
+            ValidationErrorType::BrokenExternalLink => "broken-external-link",
+            ValidationErrorType::MissingAsset => "missing-asset",
+            ValidationErrorType::AssetOutsideArticles => "asset-outside-articles",
+        }
+    }
+
+    /// Severity when the project config doesn't set one. Cycles are normal
+    /// in a wiki link graph, so they are only reported as warnings.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            ValidationErrorType::CircularReference => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl ValidationWarningType {
+    /// Identifier used in the project config and `validation_ignore`
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            ValidationWarningType::UnusedTag => "unused-tag",
+            ValidationWarningType::LowImportanceWithManyLinks => "low-importance-with-many-links",
+            ValidationWarningType::HighImportanceWithFewLinks => "high-importance-with-few-links",
+            ValidationWarningType::MissingBacklinks => "missing-backlinks",
+            ValidationWarningType::InconsistentCasing => "inconsistent-casing",
+            ValidationWarningType::LinkToDraft => "link-to-draft",
+        }
+    }
+}
+
+/// Every rule id, in the order they are documented
+pub const VALIDATION_RULES: &[&str] = &[
+    "broken-link",
+    "broken-section-link",
+    "invalid-related-article",
+    "missing-metadata",
+    "invalid-metadata",
+    "circular-reference",
+    "orphaned-article",
+    "broken-external-link",
+    "missing-asset",
+    "asset-outside-articles",
+    "unused-tag",
+    "low-importance-with-many-links",
+    "high-importance-with-few-links",
+    "missing-backlinks",
+    "inconsistent-casing",
+    "link-to-draft",
+];
+
+/// How a validation rule is reported. `Off` is only meaningful in the config.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Off,
+}
+
+impl Severity {
+    fn error() -> Self {
+        Severity::Error
+    }
+
+    fn warning() -> Self {
+        Severity::Warning
+    }
 }
 
 /// Represents a validation error
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub error_type: ValidationErrorType,
+    #[serde(default = "Severity::error")]
+    pub severity: Severity,
     pub source_article: String,
     pub target_reference: String,
     pub context: Option<String>,
     pub line_number: Option<usize>,
+    #[serde(default)]
+    pub column_number: Option<usize>,
+    /// Source file of `source_article`
+    #[serde(default)]
+    pub file_path: Option<String>,
     pub suggestion: Option<String>,
 }
 
@@ -239,14 +446,30 @@ pub struct ValidationError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationWarning {
     pub warning_type: ValidationWarningType,
+    #[serde(default = "Severity::warning")]
+    pub severity: Severity,
     pub source_article: String,
     pub target_reference: Option<String>,
     pub context: Option<String>,
+    /// Source file of `source_article`
+    #[serde(default)]
+    pub file_path: Option<String>,
     pub suggestion: Option<String>,
 }
 
+/// A broken reference rewritten by [`LinkValidator::fix_article_source`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkFix {
+    /// 1-based line in the source file
+    pub line: usize,
+    pub original: String,
+    pub replacement: String,
+    /// Similarity between `original` and `replacement` (0.0 to 1.0)
+    pub confidence: f64,
+}
+
 /// Summary statistics for validation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValidationSummary {
     pub total_articles: usize,
     pub total_links: usize,
@@ -256,6 +479,16 @@ pub struct ValidationSummary {
     pub circular_references: usize,
     pub articles_with_errors: usize,
     pub articles_with_warnings: usize,
+    #[serde(default)]
+    pub broken_external_links: usize,
+    #[serde(default)]
+    pub missing_assets: usize,
+    /// Issues reported with error severity, from either list
+    #[serde(default)]
+    pub total_errors: usize,
+    /// Issues reported with warning severity, from either list
+    #[serde(default)]
+    pub total_warnings: usize,
 }
 
 /// Complete validation report
@@ -265,7 +498,135 @@ pub struct ValidationReport {
     pub summary: ValidationSummary,
     pub errors: Vec<ValidationError>,
     pub warnings: Vec<ValidationWarning>,
-    pub article_stats: HashMap<String, ArticleValidationStats>,
+    pub article_stats: BTreeMap<String, ArticleValidationStats>,
+}
+
+impl ValidationReport {
+    /// Recompute per-article flags and the summary from the issue lists,
+    /// after issues were added, removed or had their severity changed
+    pub fn refresh_summary(&mut self) {
+        for stats in self.article_stats.values_mut() {
+            stats.has_errors = false;
+            stats.has_warnings = false;
+        }
+        let issues = self.errors.iter().map(|e| (&e.source_article, e.severity))
+            .chain(self.warnings.iter().map(|w| (&w.source_article, w.severity)));
+        for (slug, severity) in issues {
+            if let Some(stats) = self.article_stats.get_mut(slug) {
+                match severity {
+                    Severity::Error => stats.has_errors = true,
+                    Severity::Warning => stats.has_warnings = true,
+                    Severity::Off => {}
+                }
+            }
+        }
+        
+        let errors = &self.errors;
+        let count = |error_type: &[ValidationErrorType]| {
+            errors.iter().filter(|e| error_type.contains(&e.error_type)).count()
+        };
+        let severities: Vec<Severity> = self.errors.iter().map(|e| e.severity)
+            .chain(self.warnings.iter().map(|w| w.severity))
+            .collect();
+        
+        self.summary = ValidationSummary {
+            total_articles: self.article_stats.len(),
+            total_links: self.article_stats.values().map(|s| s.outbound_links).sum(),
+            broken_links: count(&[ValidationErrorType::BrokenLink, ValidationErrorType::BrokenSectionLink]),
+            invalid_references: count(&[ValidationErrorType::InvalidRelatedArticle]),
+            orphaned_articles: count(&[ValidationErrorType::OrphanedArticle]),
+            circular_references: count(&[ValidationErrorType::CircularReference]),
+            broken_external_links: count(&[ValidationErrorType::BrokenExternalLink]),
+            missing_assets: count(&[ValidationErrorType::MissingAsset]),
+            articles_with_errors: self.article_stats.values().filter(|s| s.has_errors).count(),
+            articles_with_warnings: self.article_stats.values().filter(|s| s.has_warnings).count(),
+            total_errors: severities.iter().filter(|s| **s == Severity::Error).count(),
+            total_warnings: severities.iter().filter(|s| **s == Severity::Warning).count(),
+        };
+    }
+}
+
+/// Bump when the baseline file layout changes
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Known validation errors that should not fail later runs, e.g. legacy
+/// articles that intentionally contain broken links. Entries are keyed by
+/// (source, target, type) so they survive unrelated edits that move lines.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ValidationBaseline {
+    pub version: u32,
+    pub entries: BTreeSet<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BaselineEntry {
+    pub source_article: String,
+    pub target_reference: String,
+    pub error_type: ValidationErrorType,
+}
+
+impl From<&ValidationError> for BaselineEntry {
+    fn from(error: &ValidationError) -> Self {
+        Self {
+            source_article: error.source_article.clone(),
+            target_reference: error.target_reference.clone(),
+            error_type: error.error_type.clone(),
+        }
+    }
+}
+
+impl ValidationBaseline {
+    /// Baseline accepting every error currently in `report`
+    pub fn from_report(report: &ValidationReport) -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: report.errors.iter().map(BaselineEntry::from).collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline from {:?}", path))?;
+        let baseline: Self = serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse baseline {:?}", path))?;
+
+        if baseline.version != BASELINE_VERSION {
+            return Err(anyhow::anyhow!(
+                "Baseline {:?} has version {}, expected {}; regenerate it with --update-baseline",
+                path, baseline.version, BASELINE_VERSION
+            ));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .context("Failed to serialize baseline")?;
+        write_atomically(path, format!("{}\n", json))
+            .with_context(|| format!("Failed to write baseline to {:?}", path))
+    }
+
+    pub fn contains(&self, error: &ValidationError) -> bool {
+        self.entries.contains(&BaselineEntry::from(error))
+    }
+
+    /// Remove baselined errors from `report` and refresh its summary.
+    /// Returns how many errors were removed.
+    pub fn filter_report(&self, report: &mut ValidationReport) -> usize {
+        let before = report.errors.len();
+        report.errors.retain(|error| !self.contains(error));
+        report.refresh_summary();
+        before - report.errors.len()
+    }
+
+    /// Drop entries that `report` no longer contains, i.e. issues that have
+    /// been fixed. `report` must be unfiltered. Returns the removed entries.
+    pub fn prune(&mut self, report: &ValidationReport) -> Vec<BaselineEntry> {
+        let current: BTreeSet<BaselineEntry> = report.errors.iter().map(BaselineEntry::from).collect();
+        let resolved = self.entries.iter().filter(|entry| !current.contains(entry)).cloned().collect();
+        self.entries.retain(|entry| current.contains(entry));
+        resolved
+    }
 }
 
 /// Per-article validation statistics
@@ -283,8 +644,118 @@ pub struct ArticleValidationStats {
 pub struct LinkValidator {
     existing_articles: HashSet<String>,
     article_map: HashMap<String, ProcessedArticleRef>,
+    options: ValidationOptions,
+    broken_external_links: Vec<BrokenExternalLink>,
+    asset_issues: Vec<AssetIssue>,
+    invalid_front_matter: Vec<InvalidFrontMatter>,
+}
+
+/// Tunable validation thresholds and rule severities, read from the
+/// `[validation]` table of the project config
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidationOptions {
+    /// Shortest cycle reported as a circular reference. The default of 3
+    /// ignores bidirectional pairs (a → b → a), which are expected.
+    pub min_cycle_length: usize,
+    /// Minimum similarity for "Did you mean" suggestions (0.0 to 1.0)
+    pub similarity_threshold: f64,
+    /// Most "Did you mean" suggestions listed for one broken reference
+    pub max_suggestions: usize,
+    /// Minimum similarity for `validate-links --fix` to apply a suggestion
+    pub fix_threshold: f64,
+    /// Importance at or above which an article should have `high_importance_min_inbound` links
+    pub high_importance: u8,
+    pub high_importance_min_inbound: usize,
+    /// Importance at or below which `low_importance_max_inbound` links or more are flagged
+    pub low_importance: u8,
+    pub low_importance_max_inbound: usize,
+    /// Severity overrides keyed by rule id, see [`VALIDATION_RULES`]
+    pub rules: BTreeMap<String, Severity>,
+    /// Settings for `validate-links --check-external`
+    pub external: ExternalLinkOptions,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            min_cycle_length: 3,
+            similarity_threshold: 0.5,
+            max_suggestions: 3,
+            fix_threshold: 0.75,
+            high_importance: 4,
+            high_importance_min_inbound: 2,
+            low_importance: 2,
+            low_importance_max_inbound: 5,
+            rules: BTreeMap::new(),
+            external: ExternalLinkOptions::default(),
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// Configured severity of a rule, or `default` when not overridden
+    pub fn severity(&self, rule_id: &str, default: Severity) -> Severity {
+        self.rules.get(rule_id).copied().unwrap_or(default)
+    }
+
+    /// Reject rule ids that don't exist, so typos don't silently do nothing
+    pub fn check_rules(&self) -> Result<()> {
+        for rule_id in self.rules.keys() {
+            if !VALIDATION_RULES.contains(&rule_id.as_str()) {
+                return Err(anyhow::anyhow!(
+                    "Unknown validation rule '{}'. Known rules: {}",
+                    rule_id,
+                    VALIDATION_RULES.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Default location of the project config, relative to the working directory
+pub const PROJECT_CONFIG_FILE: &str = "khimoo.toml";
+
+/// Contents of the project config file (`khimoo.toml`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub validation: ValidationOptions,
 }
 
+impl ProjectConfig {
+    /// Read and check a config file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+        let config: ProjectConfig = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {:?}", path))?;
+        config.validation.check_rules()
+            .with_context(|| format!("Invalid config file: {:?}", path))?;
+        Ok(config)
+    }
+
+    /// Read `path` if given, otherwise `khimoo.toml` when it exists,
+    /// falling back to the defaults
+    pub fn load_or_default(path: Option<&Path>) -> Result<Self> {
+        match path {
+            Some(path) => Self::load(path),
+            None if Path::new(PROJECT_CONFIG_FILE).exists() => Self::load(Path::new(PROJECT_CONFIG_FILE)),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+/// Stop enumerating cycles in a graph after this many; densely linked
+/// articles can have exponentially many
+const MAX_REPORTED_CYCLES: usize = 100;
+
+/// Edges followed by [`find_cycles`] before it gives up. The number of
+/// paths in a dense graph grows exponentially, and short cycles below the
+/// minimum length don't count toward [`MAX_REPORTED_CYCLES`].
+const MAX_CYCLE_SEARCH_STEPS: usize = 100_000;
+
 /// Reference to a processed article for validation
 #[derive(Debug, Clone)]
 pub struct ProcessedArticleRef {
@@ -293,11 +764,19 @@ pub struct ProcessedArticleRef {
     pub metadata: ArticleMetadata,
     pub outbound_links: Vec<ExtractedLink>,
     pub file_path: String,
+    /// Heading ids, see [`heading_anchors`]
+    pub headings: Vec<String>,
+    pub has_front_matter: bool,
 }
 
 impl LinkValidator {
     /// Create a new link validator with article data
     pub fn new(articles: &[ProcessedArticleRef]) -> Self {
+        Self::with_options(articles, ValidationOptions::default())
+    }
+
+    /// Create a link validator with custom thresholds
+    pub fn with_options(articles: &[ProcessedArticleRef], options: ValidationOptions) -> Self {
         let existing_articles: HashSet<String> = articles
             .iter()
             .map(|a| a.slug.clone())
@@ -311,14 +790,39 @@ impl LinkValidator {
         Self {
             existing_articles,
             article_map,
+            options,
+            broken_external_links: Vec::new(),
+            asset_issues: Vec::new(),
+            invalid_front_matter: Vec::new(),
         }
     }
 
+    /// Report the results of an external link check, see
+    /// [`ExternalLinkChecker`](crate::external_links::ExternalLinkChecker)
+    pub fn with_broken_external_links(mut self, broken_external_links: Vec<BrokenExternalLink>) -> Self {
+        self.broken_external_links = broken_external_links;
+        self
+    }
+
+    /// Report local images that don't exist or that are outside the
+    /// articles directory, see [`find_asset_issues`]
+    pub fn with_asset_issues(mut self, asset_issues: Vec<AssetIssue>) -> Self {
+        self.asset_issues = asset_issues;
+        self
+    }
+
+    /// Report front matter problems found by
+    /// [`FrontMatterParser::check_strict`]
+    pub fn with_invalid_front_matter(mut self, invalid_front_matter: Vec<InvalidFrontMatter>) -> Self {
+        self.invalid_front_matter = invalid_front_matter;
+        self
+    }
+
     /// Validate all articles and generate a comprehensive report
     pub fn validate_all(&self) -> Result<ValidationReport> {
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
-        let mut article_stats = HashMap::new();
+        let mut article_stats = BTreeMap::new();
         
         // Validate each article
         for article in self.article_map.values() {
@@ -329,16 +833,83 @@ impl LinkValidator {
             article_stats.insert(article.slug.clone(), stats);
         }
         
-        // Generate summary statistics
-        let summary = self.generate_summary(&errors, &warnings, &article_stats);
-        
-        Ok(ValidationReport {
+        // Cycles, reachability, tags and categories span several articles,
+        // so they are checked on the whole collection
+        errors.extend(self.find_circular_references());
+        errors.extend(self.find_orphaned_articles());
+        errors.extend(self.broken_external_links.iter().map(|broken| ValidationError {
+            error_type: ValidationErrorType::BrokenExternalLink,
+            severity: Severity::Error,
+            source_article: broken.source_article.clone(),
+            target_reference: broken.link.url.clone(),
+            context: Some(broken.status.describe()),
+            line_number: Some(broken.link.line),
+            column_number: Some(broken.link.column),
+            file_path: Some(broken.file_path.clone()),
+            suggestion: None,
+        }));
+        errors.extend(self.asset_issues.iter().map(|issue| {
+            let (error_type, context) = match issue.kind {
+                AssetIssueKind::Missing => (ValidationErrorType::MissingAsset, "File not found"),
+                AssetIssueKind::OutsideArticles => (ValidationErrorType::AssetOutsideArticles, "Outside the articles directory"),
+            };
+            ValidationError {
+                error_type,
+                severity: Severity::Error,
+                source_article: issue.source_article.clone(),
+                target_reference: issue.reference.clone(),
+                context: Some(format!("{}: {}", context, issue.resolved_path.display())),
+                line_number: issue.line,
+                column_number: issue.column,
+                file_path: Some(issue.file_path.clone()),
+                suggestion: None,
+            }
+        }));
+        errors.extend(self.invalid_front_matter.iter().flat_map(|invalid| {
+            invalid.errors.0.iter().map(|error| ValidationError {
+                error_type: ValidationErrorType::InvalidMetadata,
+                severity: Severity::Error,
+                source_article: invalid.source_article.clone(),
+                target_reference: match &error.kind {
+                    FrontMatterErrorKind::UnknownKey { key, .. } => key.clone(),
+                    FrontMatterErrorKind::InvalidValue { key: Some(key) } => key.clone(),
+                    _ => "front matter".to_string(),
+                },
+                context: Some(error.message.clone()),
+                line_number: error.line,
+                column_number: error.column,
+                file_path: Some(invalid.file_path.clone()),
+                suggestion: None,
+            })
+        }));
+        warnings.extend(self.find_unused_tags());
+        warnings.extend(self.find_inconsistent_casing());
+        warnings.extend(self.find_links_to_drafts());
+        
+        // Apply configured severities and per-article suppressions
+        self.apply_rules(&mut errors, &mut warnings);
+        
+        // The articles were visited in hash order; reports are diffed and
+        // committed, so list issues by position in the source
+        errors.sort_by(|a, b| {
+            (&a.file_path, a.line_number, a.column_number, a.error_type.rule_id(), &a.source_article, &a.target_reference)
+                .cmp(&(&b.file_path, b.line_number, b.column_number, b.error_type.rule_id(), &b.source_article, &b.target_reference))
+        });
+        warnings.sort_by(|a, b| {
+            (&a.file_path, a.warning_type.rule_id(), &a.source_article, &a.target_reference)
+                .cmp(&(&b.file_path, b.warning_type.rule_id(), &b.source_article, &b.target_reference))
+        });
+        
+        let mut report = ValidationReport {
             validation_date: chrono::Utc::now().to_rfc3339(),
-            summary,
+            summary: ValidationSummary::default(),
             errors,
             warnings,
             article_stats,
-        })
+        };
+        report.refresh_summary();
+        
+        Ok(report)
     }
 
     /// Validate a single article
@@ -352,43 +923,69 @@ impl LinkValidator {
             if !self.existing_articles.contains(&link.target_slug) {
                 errors.push(ValidationError {
                     error_type: ValidationErrorType::BrokenLink,
+                    severity: Severity::Error,
                     source_article: article.slug.clone(),
                     target_reference: link.target_slug.clone(),
                     context: Some(link.context.clone()),
-                    line_number: None, // Could be calculated from position if needed
+                    line_number: Some(link.line),
+                    column_number: Some(link.column),
+                    file_path: Some(article.file_path.clone()),
                     suggestion: self.suggest_similar_article(&link.target_slug),
                 });
                 broken_outbound_links += 1;
+            } else if let Some(section) = &link.section {
+                let target = &self.article_map[&link.target_slug];
+                if !target.headings.contains(&heading_anchor(section)) {
+                    errors.push(ValidationError {
+                        error_type: ValidationErrorType::BrokenSectionLink,
+                        severity: Severity::Error,
+                        source_article: article.slug.clone(),
+                        target_reference: format!("{}#{}", link.target_slug, section),
+                        context: Some(link.context.clone()),
+                        line_number: Some(link.line),
+                        column_number: Some(link.column),
+                        file_path: Some(article.file_path.clone()),
+                        suggestion: self.suggest_similar_heading(target, section),
+                    });
+                    broken_outbound_links += 1;
+                }
             }
         }
         
+        // Articles without front matter silently get default metadata
+        if !article.has_front_matter {
+            errors.push(ValidationError {
+                error_type: ValidationErrorType::MissingMetadata,
+                severity: Severity::Error,
+                source_article: article.slug.clone(),
+                target_reference: "front matter".to_string(),
+                context: Some(format!("No front matter, using defaults (title: \"{}\")", article.metadata.title)),
+                line_number: Some(1),
+                column_number: Some(1),
+                file_path: Some(article.file_path.clone()),
+                suggestion: Some("Add a front matter block with at least a title".to_string()),
+            });
+        }
+        
         // Validate related_articles in metadata
         let mut invalid_related_articles = 0;
         for related_slug in &article.metadata.related_articles {
             if !self.existing_articles.contains(related_slug) {
                 errors.push(ValidationError {
                     error_type: ValidationErrorType::InvalidRelatedArticle,
+                    severity: Severity::Error,
                     source_article: article.slug.clone(),
                     target_reference: related_slug.clone(),
                     context: Some("front matter related_articles".to_string()),
                     line_number: None,
+                    column_number: None,
+                    file_path: Some(article.file_path.clone()),
                     suggestion: self.suggest_similar_article(related_slug),
                 });
                 invalid_related_articles += 1;
             }
         }
         
-        // Check for circular references in related_articles
-        for related_slug in &article.metadata.related_articles {
-            if let Some(related_article) = self.article_map.get(related_slug) {
-                if related_article.metadata.related_articles.contains(&article.slug) {
-                    // This is actually a good thing (bidirectional relationship)
-                    // But we could warn if there are too many circular references
-                    continue;
-                }
-            }
-        }
-        
         // Calculate inbound links for this article
         let inbound_links = self.count_inbound_links(&article.slug);
         
@@ -407,39 +1004,282 @@ impl LinkValidator {
         Ok((errors, warnings, stats))
     }
 
-    /// Count inbound links to a specific article
-    fn count_inbound_links(&self, target_slug: &str) -> usize {
-        self.article_map
+    /// Drop issues whose rule is off or suppressed by the source article's
+    /// `validation_ignore`, and set the configured severity on the rest
+    fn apply_rules(&self, errors: &mut Vec<ValidationError>, warnings: &mut Vec<ValidationWarning>) {
+        let is_ignored = |slug: &str, rule_id: &str| {
+            self.article_map.get(slug).is_some_and(|article| {
+                article.metadata.validation_ignore
+                    .iter()
+                    .any(|ignored| ignored == rule_id || ignored == "all")
+            })
+        };
+        
+        errors.retain_mut(|error| {
+            let rule_id = error.error_type.rule_id();
+            error.severity = self.options.severity(rule_id, error.error_type.default_severity());
+            error.severity != Severity::Off && !is_ignored(&error.source_article, rule_id)
+        });
+        warnings.retain_mut(|warning| {
+            let rule_id = warning.warning_type.rule_id();
+            warning.severity = self.options.severity(rule_id, Severity::Warning);
+            warning.severity != Severity::Off && !is_ignored(&warning.source_article, rule_id)
+        });
+    }
+
+    /// Report cycles in the related_articles graph and in the link graph
+    fn find_circular_references(&self) -> Vec<ValidationError> {
+        let related_graph: BTreeMap<&str, BTreeSet<&str>> = self.article_map
             .values()
             .map(|article| {
-                article.outbound_links
+                let targets = article.metadata.related_articles
                     .iter()
-                    .filter(|link| link.target_slug == target_slug)
-                    .count()
+                    .map(String::as_str)
+                    .filter(|slug| self.existing_articles.contains(*slug))
+                    .collect();
+                (article.slug.as_str(), targets)
             })
-            .sum()
+            .collect();
+        
+        let link_graph: BTreeMap<&str, BTreeSet<&str>> = self.article_map
+            .values()
+            .map(|article| {
+                let targets = article.outbound_links
+                    .iter()
+                    .map(|link| link.target_slug.as_str())
+                    .filter(|slug| self.existing_articles.contains(*slug))
+                    .collect();
+                (article.slug.as_str(), targets)
+            })
+            .collect();
+        
+        let mut errors = Vec::new();
+        for (graph, context) in [(&related_graph, "front matter related_articles"), (&link_graph, "article links")] {
+            for cycle in find_cycles(graph, self.options.min_cycle_length) {
+                let source = &self.article_map[&cycle[0]];
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::CircularReference,
+                    severity: Severity::Warning,
+                    source_article: source.slug.clone(),
+                    // Rest of the path back to the source, so the formatted error
+                    // reads `a → b → c → a`
+                    target_reference: format!("{} → {}", cycle[1..].join(" → "), cycle[0]),
+                    context: Some(format!("{}, cycle of {} articles", context, cycle.len())),
+                    line_number: None,
+                    column_number: None,
+                    file_path: Some(source.file_path.clone()),
+                    suggestion: Some("Consider whether every link in this cycle is needed".to_string()),
+                });
+            }
+        }
+        errors
     }
 
-    /// Generate warnings for an article based on its characteristics
-    fn generate_article_warnings(&self, article: &ProcessedArticleRef, inbound_links: usize, warnings: &mut Vec<ValidationWarning>) {
-        // Warning: High importance but few inbound links
-        if article.metadata.importance >= 4 && inbound_links < 2 {
-            warnings.push(ValidationWarning {
-                warning_type: ValidationWarningType::HighImportanceWithFewLinks,
-                source_article: article.slug.clone(),
-                target_reference: None,
-                context: Some(format!("Importance: {}, Inbound links: {}", article.metadata.importance, inbound_links)),
-                suggestion: Some("Consider adding more links to this important article or reducing its importance level".to_string()),
-            });
-        }
-        
+    /// Report articles that can't be reached by following links and
+    /// related_articles from the home and author articles
+    fn find_orphaned_articles(&self) -> Vec<ValidationError> {
+        let mut reachable: HashSet<&str> = HashSet::new();
+        let mut queue: Vec<&str> = self.article_map
+            .values()
+            .filter(|article| article.metadata.home_display || article.metadata.author_image.is_some())
+            .map(|article| article.slug.as_str())
+            .collect();
+        
+        // Without entry points every article would be reported
+        if queue.is_empty() {
+            return Vec::new();
+        }
+        
+        while let Some(slug) = queue.pop() {
+            if !reachable.insert(slug) {
+                continue;
+            }
+            let article = &self.article_map[slug];
+            let targets = article.outbound_links
+                .iter()
+                .map(|link| link.target_slug.as_str())
+                .chain(article.metadata.related_articles.iter().map(String::as_str));
+            for target in targets {
+                if self.existing_articles.contains(target) && !reachable.contains(target) {
+                    queue.push(target);
+                }
+            }
+        }
+        
+        let mut orphans: Vec<&ProcessedArticleRef> = self.article_map
+            .values()
+            .filter(|article| !reachable.contains(article.slug.as_str()))
+            .collect();
+        orphans.sort_by(|a, b| a.slug.cmp(&b.slug));
+        
+        orphans
+            .into_iter()
+            .map(|article| ValidationError {
+                error_type: ValidationErrorType::OrphanedArticle,
+                severity: Severity::Error,
+                source_article: article.slug.clone(),
+                target_reference: "home".to_string(),
+                context: Some("Not reachable from the home or author articles".to_string()),
+                line_number: None,
+                column_number: None,
+                file_path: Some(article.file_path.clone()),
+                suggestion: Some("Link to this article from a reachable article or set home_display: true".to_string()),
+            })
+            .collect()
+    }
+
+    /// Report tags used by a single article (ignoring case)
+    fn find_unused_tags(&self) -> Vec<ValidationWarning> {
+        let mut tag_usage: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+        for article in self.article_map.values() {
+            for tag in &article.metadata.tags {
+                tag_usage.entry(tag.to_lowercase()).or_default().insert(article.slug.as_str());
+            }
+        }
+        
+        let mut warnings = Vec::new();
+        for (tag, slugs) in tag_usage.into_iter().filter(|(_, slugs)| slugs.len() == 1) {
+            let slug = slugs.into_iter().next().unwrap();
+            let spelling = self.article_map[slug].metadata.tags
+                .iter()
+                .find(|t| t.to_lowercase() == tag)
+                .cloned()
+                .unwrap_or(tag);
+            warnings.push(ValidationWarning {
+                warning_type: ValidationWarningType::UnusedTag,
+                severity: Severity::Warning,
+                source_article: slug.to_string(),
+                target_reference: Some(spelling),
+                context: Some("Tag is not used by any other article".to_string()),
+                file_path: Some(self.article_map[slug].file_path.clone()),
+                suggestion: Some("Reuse an existing tag or remove this one".to_string()),
+            });
+        }
+        warnings
+    }
+
+    /// Report tags and categories spelled with different casing across articles
+    fn find_inconsistent_casing(&self) -> Vec<ValidationWarning> {
+        let mut tags: BTreeMap<String, BTreeMap<&str, BTreeSet<&str>>> = BTreeMap::new();
+        let mut categories: BTreeMap<String, BTreeMap<&str, BTreeSet<&str>>> = BTreeMap::new();
+        for article in self.article_map.values() {
+            for tag in &article.metadata.tags {
+                tags.entry(tag.to_lowercase()).or_default().entry(tag).or_default().insert(&article.slug);
+            }
+            if let Some(category) = &article.metadata.category {
+                categories.entry(category.to_lowercase()).or_default().entry(category).or_default().insert(&article.slug);
+            }
+        }
+        
+        let mut warnings = Vec::new();
+        for (kind, groups) in [("Tag", tags), ("Category", categories)] {
+            for spellings in groups.into_values().filter(|spellings| spellings.len() > 1) {
+                // Prefer the most used spelling; ties go to the first in sort order
+                let (preferred, preferred_slugs) = spellings
+                    .iter()
+                    .fold(None, |best: Option<(&str, &BTreeSet<&str>)>, (spelling, slugs)| match best {
+                        Some((_, best_slugs)) if best_slugs.len() >= slugs.len() => best,
+                        _ => Some((spelling, slugs)),
+                    })
+                    .unwrap();
+                for (spelling, slugs) in spellings.iter().filter(|(spelling, _)| **spelling != preferred) {
+                    for slug in slugs {
+                        warnings.push(ValidationWarning {
+                            warning_type: ValidationWarningType::InconsistentCasing,
+                            severity: Severity::Warning,
+                            source_article: slug.to_string(),
+                            target_reference: Some(spelling.to_string()),
+                            context: Some(format!("{} '{}' is also written '{}'", kind, spelling, preferred)),
+                            file_path: Some(self.article_map[*slug].file_path.clone()),
+                            suggestion: Some(format!("Use '{}' (used by {} articles)", preferred, preferred_slugs.len())),
+                        });
+                    }
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Report published articles linking to drafts or articles scheduled
+    /// for later, which process-articles leaves out of the build
+    fn find_links_to_drafts(&self) -> Vec<ValidationWarning> {
+        let now = Utc::now();
+        let mut articles: Vec<&ProcessedArticleRef> = self.article_map
+            .values()
+            .filter(|article| article.metadata.is_published(now))
+            .collect();
+        articles.sort_by(|a, b| a.slug.cmp(&b.slug));
+        
+        let mut warnings = Vec::new();
+        for article in articles {
+            let targets: BTreeSet<&str> = article.outbound_links
+                .iter()
+                .map(|link| link.target_slug.as_str())
+                .collect();
+            for target in targets {
+                let Some(metadata) = self.article_map.get(target).map(|t| &t.metadata) else {
+                    continue;
+                };
+                let context = if metadata.draft {
+                    "Target is a draft".to_string()
+                } else if let Some(publish_at) = metadata.publish_at.filter(|publish_at| *publish_at > now) {
+                    format!("Target is scheduled for {}", publish_at.format("%Y-%m-%d %H:%M UTC"))
+                } else {
+                    continue;
+                };
+                warnings.push(ValidationWarning {
+                    warning_type: ValidationWarningType::LinkToDraft,
+                    severity: Severity::Warning,
+                    source_article: article.slug.clone(),
+                    target_reference: Some(target.to_string()),
+                    context: Some(context),
+                    file_path: Some(article.file_path.clone()),
+                    suggestion: Some("The link is broken on the published site until the target is published".to_string()),
+                });
+            }
+        }
+        warnings
+    }
+
+    /// Count inbound links to a specific article
+    fn count_inbound_links(&self, target_slug: &str) -> usize {
+        self.article_map
+            .values()
+            .map(|article| {
+                article.outbound_links
+                    .iter()
+                    .filter(|link| link.target_slug == target_slug)
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Generate warnings for an article based on its characteristics
+    fn generate_article_warnings(&self, article: &ProcessedArticleRef, inbound_links: usize, warnings: &mut Vec<ValidationWarning>) {
+        let options = &self.options;
+        
+        // Warning: High importance but few inbound links
+        if article.metadata.importance >= options.high_importance && inbound_links < options.high_importance_min_inbound {
+            warnings.push(ValidationWarning {
+                warning_type: ValidationWarningType::HighImportanceWithFewLinks,
+                severity: Severity::Warning,
+                source_article: article.slug.clone(),
+                target_reference: None,
+                context: Some(format!("Importance: {}, Inbound links: {}", article.metadata.importance, inbound_links)),
+                file_path: Some(article.file_path.clone()),
+                suggestion: Some("Consider adding more links to this important article or reducing its importance level".to_string()),
+            });
+        }
+        
         // Warning: Low importance but many inbound links
-        if article.metadata.importance <= 2 && inbound_links >= 5 {
+        if article.metadata.importance <= options.low_importance && inbound_links >= options.low_importance_max_inbound {
             warnings.push(ValidationWarning {
                 warning_type: ValidationWarningType::LowImportanceWithManyLinks,
+                severity: Severity::Warning,
                 source_article: article.slug.clone(),
                 target_reference: None,
                 context: Some(format!("Importance: {}, Inbound links: {}", article.metadata.importance, inbound_links)),
+                file_path: Some(article.file_path.clone()),
                 suggestion: Some("Consider increasing the importance level of this well-connected article".to_string()),
             });
         }
@@ -448,90 +1288,321 @@ impl LinkValidator {
         if article.outbound_links.is_empty() && inbound_links == 0 {
             warnings.push(ValidationWarning {
                 warning_type: ValidationWarningType::MissingBacklinks,
+                severity: Severity::Warning,
                 source_article: article.slug.clone(),
                 target_reference: None,
                 context: Some("No inbound or outbound links".to_string()),
+                file_path: Some(article.file_path.clone()),
                 suggestion: Some("Consider adding links to/from this article to integrate it better".to_string()),
             });
         }
     }
 
-    /// Suggest similar articles for broken links using simple string similarity
-    fn suggest_similar_article(&self, broken_slug: &str) -> Option<String> {
-        let mut best_match = None;
-        let mut best_score = 0.0;
+    /// Suggest up to `max_suggestions` similar articles for a broken reference
+    pub fn suggest_similar_article(&self, broken_slug: &str) -> Option<String> {
+        let candidates: Vec<String> = self.similar_articles(broken_slug)
+            .into_iter()
+            .filter(|(_, score)| *score > self.options.similarity_threshold)
+            .take(self.options.max_suggestions)
+            .map(|(slug, _)| format!("'{}'", slug))
+            .collect();
         
-        for existing_slug in &self.existing_articles {
-            let score = self.calculate_similarity(broken_slug, existing_slug);
-            if score > best_score && score > 0.5 { // Threshold for suggestions
-                best_score = score;
-                best_match = Some(existing_slug.clone());
-            }
+        match candidates.split_last() {
+            None => None,
+            Some((only, [])) => Some(format!("Did you mean {}?", only)),
+            Some((last, rest)) => Some(format!("Did you mean {} or {}?", rest.join(", "), last)),
         }
-        
-        best_match.map(|slug| format!("Did you mean '{}'?", slug))
     }
 
-    /// Calculate simple string similarity (Jaccard similarity on character bigrams)
-    fn calculate_similarity(&self, s1: &str, s2: &str) -> f64 {
-        let bigrams1: HashSet<String> = s1.chars()
-            .collect::<Vec<_>>()
-            .windows(2)
-            .map(|w| format!("{}{}", w[0], w[1]))
+    /// Existing articles ranked by similarity to `reference`, best first.
+    /// Slugs and titles are matched directly; tags count for less, since
+    /// a shared tag means a related article rather than the intended one.
+    pub fn similar_articles(&self, reference: &str) -> Vec<(&str, f64)> {
+        self.rank_articles(reference, true)
+    }
+
+    /// Most similar existing article by slug or title, and its score
+    pub fn best_similar_article(&self, reference: &str) -> Option<(&str, f64)> {
+        self.rank_articles(reference, false).into_iter().next()
+    }
+
+    fn rank_articles(&self, reference: &str, include_tags: bool) -> Vec<(&str, f64)> {
+        let reference = slugify(reference);
+        let mut ranked: Vec<(&str, f64)> = self.article_map
+            .values()
+            .map(|article| {
+                let mut score = self.calculate_similarity(&reference, &article.slug)
+                    .max(self.calculate_similarity(&reference, &slugify(&article.title)));
+                if include_tags {
+                    for tag in &article.metadata.tags {
+                        score = score.max(TAG_MATCH_WEIGHT * self.calculate_similarity(&reference, &slugify(tag)));
+                    }
+                }
+                (article.slug.as_str(), score)
+            })
+            .filter(|(_, score)| *score > 0.0)
             .collect();
+        // Ties resolve by slug so results don't depend on hash order
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranked
+    }
+
+    /// Replacement slug for `broken_slug` if the best match is confident
+    /// enough and clearly better than the runner-up
+    fn fix_target(&self, broken_slug: &str) -> Option<(&str, f64)> {
+        let ranked = self.rank_articles(broken_slug, false);
+        let (slug, score) = *ranked.first()?;
+        let ambiguous = ranked.get(1).is_some_and(|(_, runner_up)| *runner_up == score);
+        (score >= self.options.fix_threshold && !ambiguous).then_some((slug, score))
+    }
+
+    /// Rewrite broken links and invalid `related_articles` entries in
+    /// `content`, the unparsed source of `slug`, to their confident
+    /// suggestions. Everything outside the rewritten targets is kept
+    /// byte for byte; references that can't be located exactly are left alone.
+    pub fn fix_article_source(&self, slug: &str, content: &str) -> (String, Vec<LinkFix>) {
+        let Some(article) = self.article_map.get(slug) else {
+            return (content.to_string(), Vec::new());
+        };
         
-        let bigrams2: HashSet<String> = s2.chars()
-            .collect::<Vec<_>>()
-            .windows(2)
-            .map(|w| format!("{}{}", w[0], w[1]))
-            .collect();
+        let mut edits: Vec<(Range<usize>, LinkFix)> = Vec::new();
+        for link in &article.outbound_links {
+            if self.existing_articles.contains(&link.target_slug) {
+                continue;
+            }
+            let Some((replacement, confidence)) = self.fix_target(&link.target_slug) else {
+                continue;
+            };
+            let Some(range) = link_target_range(content, link) else {
+                continue;
+            };
+            edits.push((range.clone(), LinkFix {
+                line: link.line,
+                original: content[range].to_string(),
+                replacement: replacement.to_string(),
+                confidence,
+            }));
+        }
         
-        if bigrams1.is_empty() && bigrams2.is_empty() {
-            return 1.0;
+        for related_slug in &article.metadata.related_articles {
+            if self.existing_articles.contains(related_slug) {
+                continue;
+            }
+            let Some((replacement, confidence)) = self.fix_target(related_slug) else {
+                continue;
+            };
+            let Some(range) = related_article_range(content, related_slug) else {
+                continue;
+            };
+            edits.push((range.clone(), LinkFix {
+                line: line_column(content, range.start).0,
+                original: related_slug.clone(),
+                replacement: replacement.to_string(),
+                confidence,
+            }));
         }
         
-        let intersection = bigrams1.intersection(&bigrams2).count();
-        let union = bigrams1.union(&bigrams2).count();
+        // Apply back to front so earlier ranges stay valid
+        edits.sort_by_key(|(range, _)| range.start);
+        edits.dedup_by(|later, earlier| later.0.start < earlier.0.end);
+        let mut fixed = content.to_string();
+        for (range, fix) in edits.iter().rev() {
+            fixed.replace_range(range.clone(), &fix.replacement);
+        }
         
-        intersection as f64 / union as f64
+        (fixed, edits.into_iter().map(|(_, fix)| fix).collect())
     }
 
-    /// Generate summary statistics
-    fn generate_summary(&self, errors: &[ValidationError], warnings: &[ValidationWarning], article_stats: &HashMap<String, ArticleValidationStats>) -> ValidationSummary {
-        let total_articles = self.article_map.len();
-        let total_links: usize = article_stats.values().map(|s| s.outbound_links).sum();
-        
-        let broken_links = errors.iter()
-            .filter(|e| matches!(e.error_type, ValidationErrorType::BrokenLink))
-            .count();
-        
-        let invalid_references = errors.iter()
-            .filter(|e| matches!(e.error_type, ValidationErrorType::InvalidRelatedArticle))
-            .count();
-        
-        let orphaned_articles = warnings.iter()
-            .filter(|w| matches!(w.warning_type, ValidationWarningType::MissingBacklinks))
-            .count();
-        
-        let articles_with_errors = article_stats.values()
-            .filter(|s| s.has_errors)
-            .count();
-        
-        let articles_with_warnings = article_stats.values()
-            .filter(|s| s.has_warnings)
-            .count();
-        
-        ValidationSummary {
-            total_articles,
-            total_links,
-            broken_links,
-            invalid_references,
-            orphaned_articles,
-            circular_references: 0, // TODO: Implement circular reference detection
-            articles_with_errors,
-            articles_with_warnings,
+    /// Suggest an existing heading of `target` for a broken `#section` link
+    pub fn suggest_similar_heading(&self, target: &ProcessedArticleRef, section: &str) -> Option<String> {
+        let anchor = heading_anchor(section);
+        target.headings
+            .iter()
+            .map(|heading| (heading, self.calculate_similarity(&anchor, heading)))
+            .filter(|(_, score)| *score > self.options.similarity_threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(heading, _)| format!("Did you mean '{}#{}'?", target.slug, heading))
+    }
+
+    /// Similarity of two strings from 0.0 to 1.0, see [`text_similarity`]
+    pub fn calculate_similarity(&self, s1: &str, s2: &str) -> f64 {
+        text_similarity(s1, s2)
+    }
+}
+
+/// Weight of a tag match relative to a slug or title match
+const TAG_MATCH_WEIGHT: f64 = 0.7;
+
+/// Similarity of two strings from 0.0 to 1.0, mostly normalized edit
+/// distance (typos, missing characters) with some token overlap (reordered
+/// words). Works on characters, so Japanese text is compared per character
+/// and its tokens are character bigrams.
+pub fn text_similarity(s1: &str, s2: &str) -> f64 {
+    let a: Vec<char> = s1.to_lowercase().chars().collect();
+    let b: Vec<char> = s2.to_lowercase().chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    
+    let edit = 1.0 - edit_distance(&a, &b) as f64 / max_len as f64;
+    
+    let tokens1 = similarity_tokens(s1);
+    let tokens2 = similarity_tokens(s2);
+    let union = tokens1.union(&tokens2).count();
+    let overlap = if union == 0 {
+        0.0
+    } else {
+        tokens1.intersection(&tokens2).count() as f64 / union as f64
+    };
+    
+    0.8 * edit.max(overlap) + 0.2 * edit.min(overlap)
+}
+
+/// Levenshtein distance between two character sequences
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
         }
+        std::mem::swap(&mut previous, &mut current);
     }
+    previous[b.len()]
+}
+
+/// Words of `text` split on anything that isn't alphanumeric. Runs of
+/// non-ASCII characters (e.g. Japanese, which has no spaces) are split
+/// into character bigrams instead.
+fn similarity_tokens(text: &str) -> HashSet<String> {
+    let mut tokens = HashSet::new();
+    for word in text.to_lowercase().split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        let chars: Vec<char> = word.chars().collect();
+        if word.is_ascii() || chars.len() < 2 {
+            tokens.insert(word.to_string());
+        } else {
+            tokens.extend(chars.windows(2).map(|pair| pair.iter().collect::<String>()));
+        }
+    }
+    tokens
+}
+
+/// Byte offset of a 1-based line and character column
+fn byte_offset(content: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start: usize = content.split_inclusive('\n').take(line.checked_sub(1)?).map(str::len).sum();
+    let line_text = content.get(line_start..)?.split('\n').next()?;
+    let column_offset = line_text.char_indices().map(|(i, _)| i).chain([line_text.len()]).nth(column.checked_sub(1)?)?;
+    Some(line_start + column_offset)
+}
+
+/// Byte range of the article target inside a link's source text, e.g.
+/// `Tokio Basics` in `[[Tokio Basics#setup|label]]` or `slug` in `[text](slug)`
+fn link_target_range(content: &str, link: &ExtractedLink) -> Option<Range<usize>> {
+    let start = byte_offset(content, link.line, link.column)?;
+    if !content[start..].starts_with(&link.original_text) {
+        return None;
+    }
+    
+    let text = &link.original_text;
+    let (search_from, target) = match link.link_type {
+        LinkType::WikiLink => {
+            let inner = text.strip_prefix("[[")?.strip_suffix("]]")?;
+            (2, WikiLink::parse(inner).target)
+        }
+        LinkType::MarkdownLink => (text.rfind("](")? + 2, link.target_slug.as_str()),
+    };
+    let target_start = start + search_from + text[search_from..].find(target)?;
+    Some(target_start..target_start + target.len())
+}
+
+/// Byte range of `slug` within the `related_articles` list of the front matter
+fn related_article_range(content: &str, slug: &str) -> Option<Range<usize>> {
+    let start = if content.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 };
+    let delimiter = FRONT_MATTER_FORMATS
+        .iter()
+        .map(|format| format.delimiter())
+        .find(|delimiter| content[start..].starts_with(delimiter))?;
+    let front_matter_end = content[start + delimiter.len()..]
+        .find(&format!("\n{}", delimiter))
+        .map(|end| start + delimiter.len() + end)?;
+    let front_matter = &content[..front_matter_end];
+    // `related_articles:` in YAML, `related_articles =` in TOML
+    let key = front_matter
+        .match_indices("\nrelated_articles")
+        .map(|(i, key)| i + key.len())
+        .find_map(|end| {
+            let rest = &front_matter[end..];
+            let value = rest.trim_start_matches([' ', '\t']);
+            value.starts_with([':', '=']).then(|| end + (rest.len() - value.len()) + 1)
+        })?;
+    
+    // The list ends at the next top-level key
+    let list_end = front_matter[key..]
+        .match_indices('\n')
+        .map(|(i, _)| key + i)
+        .find(|&i| front_matter[i + 1..].starts_with(|c: char| !c.is_whitespace() && c != '-'))
+        .unwrap_or(front_matter.len());
+    
+    let is_delimiter = |c: Option<char>| c.is_none_or(|c| c.is_whitespace() || "[],\"'".contains(c));
+    front_matter[key..list_end]
+        .match_indices(slug)
+        .map(|(i, _)| key + i)
+        .find(|&start| {
+            let end = start + slug.len();
+            is_delimiter(front_matter[..start].chars().next_back()) && is_delimiter(front_matter[end..].chars().next())
+        })
+        .map(|start| start..start + slug.len())
+}
+
+/// Find the elementary cycles with at least `min_length` nodes in a
+/// directed graph. Each cycle is returned once, starting from its
+/// smallest node. The search is bounded, so on dense graphs only the
+/// cycles found within [`MAX_CYCLE_SEARCH_STEPS`] are returned.
+pub fn find_cycles(graph: &BTreeMap<&str, BTreeSet<&str>>, min_length: usize) -> Vec<Vec<String>> {
+    struct Search<'g, 'a> {
+        graph: &'g BTreeMap<&'a str, BTreeSet<&'a str>>,
+        min_length: usize,
+        steps: usize,
+        cycles: Vec<Vec<String>>,
+    }
+
+    impl<'a> Search<'_, 'a> {
+        fn exhausted(&self) -> bool {
+            self.cycles.len() >= MAX_REPORTED_CYCLES || self.steps >= MAX_CYCLE_SEARCH_STEPS
+        }
+
+        fn visit(&mut self, start: &'a str, path: &mut Vec<&'a str>) {
+            let Some(targets) = self.graph.get(path[path.len() - 1]) else { return };
+            for &next in targets {
+                if self.exhausted() {
+                    return;
+                }
+                self.steps += 1;
+                if next == start {
+                    if path.len() >= self.min_length {
+                        self.cycles.push(path.iter().map(|s| s.to_string()).collect());
+                    }
+                } else if next > start && !path.contains(&next) {
+                    // Only visit nodes after `start` so each cycle is found from its smallest node
+                    path.push(next);
+                    self.visit(start, path);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    let mut search = Search { graph, min_length, steps: 0, cycles: Vec::new() };
+    for &start in graph.keys() {
+        if search.exhausted() {
+            break;
+        }
+        search.visit(start, &mut vec![start]);
+    }
+    search.cycles
 }
 
 /// Report formatter for validation results
@@ -571,26 +1642,36 @@ impl ValidationReportFormatter {
             output.push_str(&format!("   🏝️  Orphaned articles: {}\n", report.summary.orphaned_articles));
         }
         
+        if report.summary.circular_references > 0 {
+            output.push_str(&format!("   🔄 Circular references: {}\n", report.summary.circular_references));
+        }
+        
+        if report.summary.broken_external_links > 0 {
+            output.push_str(&format!("   🌐 Broken external links: {}\n", report.summary.broken_external_links));
+        }
+        
+        if report.summary.missing_assets > 0 {
+            output.push_str(&format!("   🖼️  Missing images: {}\n", report.summary.missing_assets));
+        }
+        
         output.push_str(&format!("   📄 Articles with errors: {}\n", report.summary.articles_with_errors));
         output.push_str(&format!("   ⚠️  Articles with warnings: {}\n", report.summary.articles_with_warnings));
         
         // Errors section
-        if !report.errors.is_empty() {
+        let errors = Self::format_issues(report, Severity::Error);
+        if !errors.is_empty() {
             output.push_str("\n❌ Errors:\n");
-            for (i, error) in report.errors.iter().enumerate() {
-                output.push_str(&format!("{}. ", i + 1));
-                output.push_str(&Self::format_error(error));
-                output.push('\n');
+            for (i, error) in errors.iter().enumerate() {
+                output.push_str(&format!("{}. {}\n", i + 1, error));
             }
         }
         
         // Warnings section
-        if !report.warnings.is_empty() {
+        let warnings = Self::format_issues(report, Severity::Warning);
+        if !warnings.is_empty() {
             output.push_str("\n⚠️  Warnings:\n");
-            for (i, warning) in report.warnings.iter().enumerate() {
-                output.push_str(&format!("{}. ", i + 1));
-                output.push_str(&Self::format_warning(warning));
-                output.push('\n');
+            for (i, warning) in warnings.iter().enumerate() {
+                output.push_str(&format!("{}. {}\n", i + 1, warning));
             }
         }
         
@@ -647,22 +1728,53 @@ impl ValidationReportFormatter {
         output
     }
 
+    /// Format every issue reported with `severity`, whichever list it is in
+    pub fn format_issues(report: &ValidationReport, severity: Severity) -> Vec<String> {
+        let errors = report.errors
+            .iter()
+            .filter(|e| e.severity == severity)
+            .map(Self::format_error);
+        let warnings = report.warnings
+            .iter()
+            .filter(|w| w.severity == severity)
+            .map(Self::format_warning);
+        errors.chain(warnings).collect()
+    }
+
     /// Format a single validation error
     pub fn format_error(error: &ValidationError) -> String {
         let error_type_str = match error.error_type {
             ValidationErrorType::BrokenLink => "🔗 Broken Link",
+            ValidationErrorType::BrokenSectionLink => "🔖 Broken Section Link",
             ValidationErrorType::InvalidRelatedArticle => "📋 Invalid Related Article",
             ValidationErrorType::MissingMetadata => "📝 Missing Metadata",
             ValidationErrorType::InvalidMetadata => "❌ Invalid Metadata",
             ValidationErrorType::CircularReference => "🔄 Circular Reference",
             ValidationErrorType::OrphanedArticle => "🏝️  Orphaned Article",
+            ValidationErrorType::BrokenExternalLink => "🌐 Broken External Link",
+            ValidationErrorType::MissingAsset => "🖼️  Missing Image",
+            ValidationErrorType::AssetOutsideArticles => "🚫 Image Outside Articles",
         };
         
-        let mut formatted = format!("{}: {} → {}", 
+        let mut formatted = String::new();
+        
+        // `path:line:col` prefix so editors and terminals can jump to the link
+        if let Some(file_path) = &error.file_path {
+            formatted.push_str(file_path);
+            if let Some(line) = error.line_number {
+                formatted.push_str(&format!(":{}", line));
+                if let Some(column) = error.column_number {
+                    formatted.push_str(&format!(":{}", column));
+                }
+            }
+            formatted.push(' ');
+        }
+        
+        formatted.push_str(&format!("{}: {} → {}", 
             error_type_str, 
             error.source_article, 
             error.target_reference
-        );
+        ));
         
         if let Some(context) = &error.context {
             formatted.push_str(&format!(" ({})", context));
@@ -683,6 +1795,7 @@ impl ValidationReportFormatter {
             ValidationWarningType::HighImportanceWithFewLinks => "📉 High Importance, Few Links",
             ValidationWarningType::MissingBacklinks => "🔗 Missing Backlinks",
             ValidationWarningType::InconsistentCasing => "🔤 Inconsistent Casing",
+            ValidationWarningType::LinkToDraft => "📝 Link To Draft",
         };
         
         let mut formatted = format!("{}: {}", warning_type_str, warning.source_article);
@@ -706,15 +1819,25 @@ impl ValidationReportFormatter {
     pub fn format_ci_summary(report: &ValidationReport) -> String {
         let mut output = String::new();
         
-        if report.summary.broken_links == 0 && report.summary.invalid_references == 0 {
+        let summary = &report.summary;
+        if summary.total_errors == 0 {
             output.push_str("✅ All links valid");
         } else {
-            output.push_str("❌ Validation failed:");
-            if report.summary.broken_links > 0 {
-                output.push_str(&format!(" {} broken links", report.summary.broken_links));
-            }
-            if report.summary.invalid_references > 0 {
-                output.push_str(&format!(" {} invalid references", report.summary.invalid_references));
+            output.push_str(&format!("❌ Validation failed: {} errors", summary.total_errors));
+            let details: Vec<String> = [
+                (summary.broken_links, "broken links"),
+                (summary.invalid_references, "invalid references"),
+                (summary.orphaned_articles, "orphaned articles"),
+                (summary.circular_references, "circular references"),
+                (summary.broken_external_links, "broken external links"),
+                (summary.missing_assets, "missing images"),
+            ]
+            .into_iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, label)| format!("{} {}", count, label))
+            .collect();
+            if !details.is_empty() {
+                output.push_str(&format!(" ({})", details.join(", ")));
             }
         }
         
@@ -725,6 +1848,137 @@ impl ValidationReportFormatter {
         output
     }
 
+    /// Format validation report as SARIF 2.1.0, one result per issue.
+    /// File paths are made relative to `source_root`, which code scanning
+    /// resolves `%SRCROOT%` to; issues in files outside it have no location.
+    pub fn format_sarif(report: &ValidationReport, source_root: &Path) -> Result<String> {
+        let rules: Vec<_> = VALIDATION_RULES
+            .iter()
+            .map(|rule_id| serde_json::json!({ "id": rule_id }))
+            .collect();
+        
+        let errors = report.errors.iter().map(|e| {
+            let region = e.line_number.map(|line| (line, e.column_number.unwrap_or(1)));
+            Self::sarif_result(e.error_type.rule_id(), e.severity, &Self::issue_message(
+                &e.source_article, Some(&e.target_reference), e.context.as_deref(), e.suggestion.as_deref(),
+            ), e.file_path.as_deref().and_then(|path| Self::sarif_uri(path, source_root)), region)
+        });
+        let warnings = report.warnings.iter().map(|w| {
+            Self::sarif_result(w.warning_type.rule_id(), w.severity, &Self::issue_message(
+                &w.source_article, w.target_reference.as_deref(), w.context.as_deref(), w.suggestion.as_deref(),
+            ), w.file_path.as_deref().and_then(|path| Self::sarif_uri(path, source_root)), None)
+        });
+        
+        let sarif = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "validate-links",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "results": errors.chain(warnings).collect::<Vec<_>>(),
+            }]
+        });
+        
+        serde_json::to_string_pretty(&sarif)
+            .context("Failed to serialize validation report to SARIF")
+    }
+
+    /// `file_path` relative to `source_root` with `/` separators, or `None`
+    /// when it is outside `source_root`
+    fn sarif_uri(file_path: &str, source_root: &Path) -> Option<String> {
+        let file_path = real_path(Path::new(file_path));
+        let relative = file_path.strip_prefix(real_path(source_root)).ok()?;
+        let segments: Vec<_> = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect();
+        Some(segments.join("/"))
+    }
+
+    fn sarif_result(rule_id: &str, severity: Severity, message: &str, uri: Option<String>, region: Option<(usize, usize)>) -> serde_json::Value {
+        let mut result = serde_json::json!({
+            "ruleId": rule_id,
+            "ruleIndex": VALIDATION_RULES.iter().position(|id| *id == rule_id),
+            "level": if severity == Severity::Error { "error" } else { "warning" },
+            "message": { "text": message },
+        });
+        
+        if let Some(uri) = uri {
+            let mut physical = serde_json::json!({
+                "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+            });
+            if let Some((line, column)) = region {
+                physical["region"] = serde_json::json!({ "startLine": line, "startColumn": column });
+            }
+            result["locations"] = serde_json::json!([{ "physicalLocation": physical }]);
+        }
+        
+        result
+    }
+
+    /// Format validation report as JUnit XML with one test case per article.
+    /// Error-severity issues fail the test case; warnings go to its system-out.
+    pub fn format_junit(report: &ValidationReport) -> String {
+        let mut cases: BTreeMap<&str, (Vec<String>, Vec<String>)> = report.article_stats
+            .keys()
+            .map(|slug| (slug.as_str(), Default::default()))
+            .collect();
+        let errors = report.errors.iter().map(|e| (&e.source_article, e.severity, Self::format_error(e)));
+        let warnings = report.warnings.iter().map(|w| (&w.source_article, w.severity, Self::format_warning(w)));
+        for (slug, severity, formatted) in errors.chain(warnings) {
+            let (failures, output) = cases.entry(slug.as_str()).or_default();
+            match severity {
+                Severity::Error => failures.push(formatted),
+                _ => output.push(formatted),
+            }
+        }
+        
+        let failed = cases.values().filter(|(failures, _)| !failures.is_empty()).count();
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!("<testsuites name=\"validate-links\" tests=\"{}\" failures=\"{}\">\n", cases.len(), failed));
+        output.push_str(&format!("  <testsuite name=\"articles\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n", cases.len(), failed));
+        
+        for (slug, (failures, warnings)) in &cases {
+            output.push_str(&format!("    <testcase name=\"{}\" classname=\"articles\"", xml_escape(slug)));
+            if failures.is_empty() && warnings.is_empty() {
+                output.push_str("/>\n");
+                continue;
+            }
+            output.push_str(">\n");
+            if !failures.is_empty() {
+                output.push_str(&format!(
+                    "      <failure message=\"{} error(s)\">{}</failure>\n",
+                    failures.len(),
+                    xml_escape(&failures.join("\n"))
+                ));
+            }
+            if !warnings.is_empty() {
+                output.push_str(&format!("      <system-out>{}</system-out>\n", xml_escape(&warnings.join("\n"))));
+            }
+            output.push_str("    </testcase>\n");
+        }
+        
+        output.push_str("  </testsuite>\n</testsuites>\n");
+        output
+    }
+
+    /// Plain-text description of an issue for machine-readable formats
+    fn issue_message(source: &str, target: Option<&str>, context: Option<&str>, suggestion: Option<&str>) -> String {
+        let mut message = source.to_string();
+        if let Some(target) = target {
+            message.push_str(&format!(" → {}", target));
+        }
+        if let Some(context) = context {
+            message.push_str(&format!(" ({})", context));
+        }
+        if let Some(suggestion) = suggestion {
+            message.push_str(&format!(". {}", suggestion));
+        }
+        message
+    }
+
     /// Write validation report to files
     pub fn write_report_files(report: &ValidationReport, output_dir: &std::path::Path) -> Result<()> {
         // Ensure output directory exists
@@ -753,55 +2007,1204 @@ impl ValidationReportFormatter {
     }
 }
 
-/// Front matter parser using yaml-front-matter library
-pub struct FrontMatterParser;
+/// A front matter syntax, recognized by the delimiter lines around its
+/// block. Finding the block, unknown keys and value ranges are handled by
+/// [`FrontMatterParser`]; a format only reads the text between the lines.
+/// Lines and columns in returned errors are 1-based within that text.
+pub trait FrontMatterFormat: Sync {
+    /// Name used in messages, e.g. `YAML`
+    fn name(&self) -> &'static str;
 
-impl FrontMatterParser {
-    /// Parse front matter from markdown content using yaml-front-matter library
-    /// Returns (metadata, remaining_content)
-    pub fn parse(content: &str) -> Result<(ArticleMetadata, String)> {
-        // Try to parse with yaml-front-matter
-        match YamlFrontMatter::parse(content) {
-            Ok(Document { metadata, content: markdown_content }) => {
-                // Parse metadata into ArticleMetadata struct
-                let metadata: ArticleMetadata = serde_yaml::from_value(metadata)
-                    .context("Failed to deserialize front matter metadata")?;
-                
-                Ok((metadata, markdown_content))
-            }
-            Err(_) => {
-                // No front matter found, return default metadata and full content
-                Ok((ArticleMetadata::default(), content.to_string()))
-            }
+    /// Line that opens and closes the block, e.g. `---`
+    fn delimiter(&self) -> &'static str;
+
+    /// Top-level keys in source order. Fails with a
+    /// [`Syntax`](FrontMatterErrorKind::Syntax) error when the block
+    /// doesn't parse.
+    fn keys(&self, block: &str) -> std::result::Result<Vec<String>, FrontMatterError>;
+
+    /// Deserialize the block, reporting every invalid value found.
+    /// Syntax errors are reported like in [`keys`](Self::keys).
+    fn deserialize(&self, block: &str) -> std::result::Result<ArticleMetadata, Vec<FrontMatterError>>;
+
+    /// 1-based line within `block_lines` where top-level `key` is defined
+    fn key_line(&self, block_lines: &[&str], key: &str) -> Option<usize>;
+}
+
+/// Supported front matter formats. Front matter must start on the first
+/// line of a file, whose delimiter decides the format.
+pub const FRONT_MATTER_FORMATS: &[&dyn FrontMatterFormat] = &[&YamlFormat, &TomlFormat];
+
+/// YAML between `---` lines
+pub struct YamlFormat;
+
+impl YamlFormat {
+    fn syntax_error(e: serde_yaml::Error) -> FrontMatterError {
+        let location = e.location();
+        FrontMatterError {
+            kind: FrontMatterErrorKind::Syntax,
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+            message: format!("invalid YAML: {}", e),
         }
     }
+}
 
-    /// Validate metadata fields
-    pub fn validate_metadata(metadata: &ArticleMetadata) -> Result<()> {
-        // Validate importance range
-        if metadata.importance < 1 || metadata.importance > 5 {
-            return Err(anyhow::anyhow!(
-                "Importance must be between 1 and 5, got: {}", 
-                metadata.importance
-            ));
-        }
+impl FrontMatterFormat for YamlFormat {
+    fn name(&self) -> &'static str {
+        "YAML"
+    }
 
-        // Validate title is not empty
-        if metadata.title.trim().is_empty() {
-            return Err(anyhow::anyhow!("Title cannot be empty"));
-        }
+    fn delimiter(&self) -> &'static str {
+        "---"
+    }
+
+    fn keys(&self, block: &str) -> std::result::Result<Vec<String>, FrontMatterError> {
+        let value: serde_yaml::Value = serde_yaml::from_str(block).map_err(Self::syntax_error)?;
+        Ok(match value {
+            serde_yaml::Value::Mapping(mapping) => mapping
+                .keys()
+                .filter_map(|key| key.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        })
+    }
 
-        // Validate datetime formats if present
-        if let Some(created_at) = &metadata.created_at {
-            DateTime::parse_from_rfc3339(created_at)
-                .context("Invalid created_at datetime format")?;
+    fn deserialize(&self, block: &str) -> std::result::Result<ArticleMetadata, Vec<FrontMatterError>> {
+        let block_lines: Vec<&str> = block.lines().collect();
+        let value: serde_yaml::Value = serde_yaml::from_str(block).map_err(|e| vec![Self::syntax_error(e)])?;
+
+        // Custom deserializers don't report a location, so dates are
+        // checked per key
+        let mut errors = Vec::new();
+        for key in ["created_at", "updated_at", "publish_at"] {
+            if let Some(serde_yaml::Value::String(date)) = value.get(key) {
+                if crate::schema::parse_date(date).is_none() {
+                    errors.push(FrontMatterError {
+                        kind: FrontMatterErrorKind::InvalidValue { key: Some(key.to_string()) },
+                        line: self.key_line(&block_lines, key),
+                        column: Some(1),
+                        message: format!("{}: invalid date '{}', expected YYYY-MM-DD or an RFC 3339 date and time", key, date),
+                    });
+                }
+            }
         }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        serde_yaml::from_str(block).map_err(|e: serde_yaml::Error| {
+            // Errors not tied to a position (e.g. a missing `title`)
+            // are reported at line 1, column 1
+            let location = e.location().filter(|l| l.line() > 1 || l.column() > 1);
+            vec![FrontMatterError {
+                kind: FrontMatterErrorKind::InvalidValue {
+                    key: location.as_ref().and_then(|l| front_matter_key_at(&block_lines, l.line(), ':')),
+                },
+                line: location.as_ref().map(|l| l.line()),
+                column: location.as_ref().map(|l| l.column()),
+                message: e.to_string(),
+            }]
+        })
+    }
 
-        if let Some(updated_at) = &metadata.updated_at {
-            DateTime::parse_from_rfc3339(updated_at)
-                .context("Invalid updated_at datetime format")?;
+    fn key_line(&self, block_lines: &[&str], key: &str) -> Option<usize> {
+        front_matter_key_line(block_lines, key, ':')
+    }
+}
+
+/// TOML between `+++` lines, as written by Hugo and Zola
+pub struct TomlFormat;
+
+impl TomlFormat {
+    /// 1-based line and column of a byte offset in `block`
+    fn location(block: &str, offset: usize) -> (usize, usize) {
+        let before = &block[..offset.min(block.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+    }
+
+    fn error(block: &str, kind: FrontMatterErrorKind, e: toml::de::Error) -> FrontMatterError {
+        let location = e.span().map(|span| Self::location(block, span.start));
+        FrontMatterError {
+            kind,
+            line: location.map(|(line, _)| line),
+            column: location.map(|(_, column)| column),
+            message: e.message().to_string(),
         }
+    }
+}
 
-        Ok(())
+impl FrontMatterFormat for TomlFormat {
+    fn name(&self) -> &'static str {
+        "TOML"
+    }
+
+    fn delimiter(&self) -> &'static str {
+        "+++"
+    }
+
+    fn keys(&self, block: &str) -> std::result::Result<Vec<String>, FrontMatterError> {
+        let table: toml::Table = toml::from_str(block)
+            .map_err(|e| Self::error(block, FrontMatterErrorKind::Syntax, e))?;
+        // Tables are sorted by key; put them back in source order
+        let block_lines: Vec<&str> = block.lines().collect();
+        let mut keys: Vec<String> = table.keys().cloned().collect();
+        keys.sort_by_key(|key| self.key_line(&block_lines, key));
+        Ok(keys)
+    }
+
+    fn deserialize(&self, block: &str) -> std::result::Result<ArticleMetadata, Vec<FrontMatterError>> {
+        self.keys(block).map_err(|e| vec![e])?;
+        let block_lines: Vec<&str> = block.lines().collect();
+        toml::from_str(block).map_err(|e| {
+            let line = e.span().map(|span| Self::location(block, span.start).0);
+            let key = line.and_then(|line| front_matter_key_at(&block_lines, line, '='));
+            vec![Self::error(block, FrontMatterErrorKind::InvalidValue { key }, e)]
+        })
     }
-}
\ No newline at end of file
+
+    fn key_line(&self, block_lines: &[&str], key: &str) -> Option<usize> {
+        front_matter_key_line(block_lines, key, '=')
+    }
+}
+
+/// Front matter block found in an article by [`FrontMatterParser::find_block`]
+struct FrontMatterBlock<'a> {
+    format: &'static dyn FrontMatterFormat,
+    /// 0-based line of the closing delimiter, `None` when it's missing
+    end: Option<usize>,
+    lines: Vec<&'a str>,
+}
+
+impl FrontMatterBlock<'_> {
+    fn block_lines(&self) -> &[&str] {
+        &self.lines[1..self.end.unwrap_or(self.lines.len())]
+    }
+
+    /// Move a location within the block to the whole file. Problems without
+    /// a position are reported on the opening delimiter.
+    fn to_file(&self, mut error: FrontMatterError) -> FrontMatterError {
+        match error.line {
+            Some(line) => error.line = Some(1 + line),
+            None => error.line = Some(1),
+        }
+        error
+    }
+}
+
+/// Front matter parser for the formats in [`FRONT_MATTER_FORMATS`]
+pub struct FrontMatterParser;
+
+impl FrontMatterParser {
+    /// Parse front matter from markdown content
+    /// Returns (metadata, remaining_content)
+    pub fn parse(content: &str) -> Result<(ArticleMetadata, String)> {
+        Self::parse_with_body_offset(content).map(|(metadata, markdown_content, _)| (metadata, markdown_content))
+    }
+
+    /// Like [`parse`](Self::parse), also returning the number of lines that
+    /// precede the body in `content` (the front matter and its delimiters)
+    pub fn parse_with_body_offset(content: &str) -> Result<(ArticleMetadata, String, usize)> {
+        // Without a complete block, or when it doesn't parse, the whole
+        // file is the body and default metadata is used
+        let Some(block) = Self::find_block(content) else {
+            return Ok((ArticleMetadata::default(), content.to_string(), 0));
+        };
+        let Some(end) = block.end else {
+            return Ok((ArticleMetadata::default(), content.to_string(), 0));
+        };
+
+        match block.format.deserialize(&block.block_lines().join("\n")) {
+            Ok(metadata) => {
+                // The body starts after the closing delimiter
+                let markdown_content = block.lines[end + 1..].join("\n");
+                Ok((metadata, markdown_content, end + 1))
+            }
+            Err(errors) if errors.iter().any(|e| e.kind == FrontMatterErrorKind::Syntax) => {
+                Ok((ArticleMetadata::default(), content.to_string(), 0))
+            }
+            Err(errors) => {
+                let errors = FrontMatterErrors(errors.into_iter().map(|e| block.to_file(e)).collect());
+                Err(anyhow::Error::new(errors)
+                    .context(format!("Failed to deserialize {} front matter metadata", block.format.name())))
+            }
+        }
+    }
+
+    /// Like [`parse_with_body_offset`](Self::parse_with_body_offset), but
+    /// rejects front matter that [`check_strict`](Self::check_strict)
+    /// finds problems with instead of falling back to defaults
+    pub fn parse_strict(content: &str) -> std::result::Result<(ArticleMetadata, String, usize), FrontMatterErrors> {
+        Self::check_strict(content)?;
+        Self::parse_with_body_offset(content).map_err(|e| {
+            FrontMatterErrors(vec![FrontMatterError {
+                kind: FrontMatterErrorKind::InvalidValue { key: None },
+                line: None,
+                column: None,
+                message: format!("{:#}", e),
+            }])
+        })
+    }
+
+    /// Check front matter without the lenient fallbacks of `parse`: the
+    /// block must parse, every key must be known, values must have the
+    /// right type and pass [`validate_metadata`](Self::validate_metadata).
+    /// Content without front matter passes. Every problem found is
+    /// returned, with its line in `content`.
+    pub fn check_strict(content: &str) -> std::result::Result<(), FrontMatterErrors> {
+        let Some(block) = Self::find_block(content) else {
+            return Ok(());
+        };
+        let format = block.format;
+        if block.end.is_none() {
+            return Err(FrontMatterErrors(vec![FrontMatterError {
+                kind: FrontMatterErrorKind::Syntax,
+                line: Some(1),
+                column: None,
+                message: format!("front matter is not closed with a `{}` line", format.delimiter()),
+            }]));
+        }
+        let block_lines = block.block_lines();
+        let text = block_lines.join("\n");
+        let key_error = |key: &str, message: String| {
+            block.to_file(FrontMatterError {
+                kind: FrontMatterErrorKind::InvalidValue { key: Some(key.to_string()) },
+                line: format.key_line(block_lines, key),
+                column: Some(1),
+                message,
+            })
+        };
+
+        let keys = format.keys(&text).map_err(|e| FrontMatterErrors(vec![block.to_file(e)]))?;
+        let mut errors = Vec::new();
+        for key in keys.iter().filter(|key| !FRONT_MATTER_KEYS.contains(&key.as_str())) {
+            let suggestion = FRONT_MATTER_KEYS
+                .iter()
+                .map(|known| (*known, text_similarity(key, known)))
+                .filter(|(_, similarity)| *similarity >= 0.5)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(known, _)| known.to_string());
+            let message = match &suggestion {
+                Some(suggestion) => format!("unknown key '{}', did you mean '{}'?", key, suggestion),
+                None => format!("unknown key '{}'", key),
+            };
+            errors.push(FrontMatterError {
+                kind: FrontMatterErrorKind::UnknownKey { key: key.to_string(), suggestion },
+                ..key_error(key, message)
+            });
+        }
+
+        match format.deserialize(&text) {
+            // Ranges are only checked once the keys are right
+            Ok(metadata) if errors.is_empty() => {
+                errors.extend(metadata_problems(&metadata).into_iter().map(|(key, message)| key_error(key, message)));
+            }
+            Ok(_) => {}
+            Err(value_errors) => errors.extend(value_errors.into_iter().map(|e| block.to_file(e))),
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(FrontMatterErrors(errors))
+        }
+    }
+
+    /// The block opened by a delimiter on the first line (after an
+    /// optional byte order mark), closed by the next line with the same
+    /// delimiter. Delimiter lines further down are thematic breaks.
+    fn find_block(content: &str) -> Option<FrontMatterBlock<'_>> {
+        let lines: Vec<&str> = content.lines().collect();
+        let first_line = lines.first()?.trim_start_matches('\u{feff}').trim();
+        let format = *FRONT_MATTER_FORMATS
+            .iter()
+            .find(|format| first_line == format.delimiter())?;
+        let end = lines[1..]
+            .iter()
+            .position(|line| line.trim() == format.delimiter())
+            .map(|offset| offset + 1);
+        Some(FrontMatterBlock { format, end, lines })
+    }
+
+    /// Validate metadata fields
+    pub fn validate_metadata(metadata: &ArticleMetadata) -> Result<()> {
+        match metadata_problems(metadata).into_iter().next() {
+            Some((_, message)) => Err(anyhow::anyhow!(message)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Keys `ArticleMetadata` reads from front matter
+pub const FRONT_MATTER_KEYS: &[&str] = &[
+    "title",
+    "home_display",
+    "category",
+    "importance",
+    "related_articles",
+    "tags",
+    "created_at",
+    "updated_at",
+    "author_image",
+    "validation_ignore",
+    "draft",
+    "publish_at",
+];
+
+/// Values that deserialize but aren't allowed, as (key, message)
+fn metadata_problems(metadata: &ArticleMetadata) -> Vec<(&'static str, String)> {
+    let mut problems = Vec::new();
+    if metadata.importance < 1 || metadata.importance > 5 {
+        problems.push(("importance", format!("Importance must be between 1 and 5, got: {}", metadata.importance)));
+    }
+    if metadata.title.trim().is_empty() {
+        problems.push(("title", "Title cannot be empty".to_string()));
+    }
+    problems
+}
+
+/// 1-based line within `block_lines` where top-level `key` is defined,
+/// as `key` followed by `separator` (`:` in YAML, `=` in TOML)
+fn front_matter_key_line(block_lines: &[&str], key: &str, separator: char) -> Option<usize> {
+    block_lines
+        .iter()
+        .position(|line| {
+            line.strip_prefix(key)
+                .or_else(|| line.strip_prefix(&format!("\"{}\"", key)))
+                .or_else(|| line.strip_prefix(&format!("'{}'", key)))
+                .is_some_and(|rest| rest.trim_start().starts_with(separator))
+        })
+        .map(|index| index + 1)
+}
+
+/// Top-level key whose value spans the 1-based `block_line`
+fn front_matter_key_at(block_lines: &[&str], block_line: usize, separator: char) -> Option<String> {
+    block_lines[..block_line.min(block_lines.len())]
+        .iter()
+        .rev()
+        .find(|line| !line.starts_with([' ', '\t', '-', '#', '[', ']']) && line.contains(separator))
+        .and_then(|line| line.split(separator).next())
+        .map(|key| key.trim().trim_matches(['"', '\'']).to_string())
+}
+
+/// What is wrong with an article's front matter, see [`FrontMatterError`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrontMatterErrorKind {
+    /// The block between the delimiter lines doesn't parse
+    Syntax,
+    /// A key `ArticleMetadata` doesn't read, usually a typo
+    UnknownKey { key: String, suggestion: Option<String> },
+    /// A value of the wrong type or out of range; `key` is `None` when
+    /// the problem isn't tied to one key (e.g. a missing `title`)
+    InvalidValue { key: Option<String> },
+}
+
+/// A problem found by [`FrontMatterParser::check_strict`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontMatterError {
+    pub kind: FrontMatterErrorKind,
+    /// 1-based line in the source file
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl std::fmt::Display for FrontMatterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: {}", line, column, self.message),
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Every problem found in one article's front matter
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontMatterErrors(pub Vec<FrontMatterError>);
+
+/// An article whose front matter failed the strict check
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidFrontMatter {
+    pub source_article: String,
+    pub file_path: String,
+    pub errors: FrontMatterErrors,
+}
+
+impl std::fmt::Display for FrontMatterErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", errors.join("; "))
+    }
+}
+
+impl std::error::Error for FrontMatterErrors {}
+
+/// Escape text for use in XML attributes and character data
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Generate a slug from free text (article titles, wiki link targets, file stems)
+pub fn slugify(text: &str) -> String {
+    let slug = text
+        .to_lowercase()
+        .trim()
+        .replace(' ', "-")
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string();
+    
+    // Replace multiple consecutive dashes with single dash
+    let re = Regex::new(r"-+").unwrap();
+    re.replace_all(&slug, "-").to_string()
+}
+
+/// Generate an article slug from its file path
+pub fn generate_slug(file_path: &Path) -> String {
+    let stem = file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    slugify(&stem)
+}
+
+impl From<&ProcessedArticle> for ProcessedArticleRef {
+    fn from(article: &ProcessedArticle) -> Self {
+        Self {
+            slug: article.slug.clone(),
+            title: article.title.clone(),
+            metadata: article.metadata.clone(),
+            outbound_links: article.outbound_links.clone(),
+            file_path: article.file_path.clone(),
+            headings: heading_anchors(&article.content),
+            has_front_matter: article.has_front_matter,
+        }
+    }
+}
+
+/// Record `dest_url` if it is an `http(s)://` link
+fn push_external_link(content: &str, start: usize, dest_url: &str, external_links: &mut Vec<ExternalLink>) {
+    if dest_url.starts_with("http://") || dest_url.starts_with("https://") {
+        let (line, column) = line_column(content, start);
+        external_links.push(ExternalLink {
+            url: dest_url.to_string(),
+            position: start,
+            line,
+            column,
+        });
+    }
+}
+
+/// Article slug a Markdown link destination (without its `#section`)
+/// refers to. `None` for URLs with a scheme (`https:`, `mailto:`, `tel:`),
+/// protocol-relative and site-absolute paths, and files other than `.md`,
+/// which are left as written.
+pub fn article_link_slug(target: &str) -> Option<&str> {
+    let has_scheme = target.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+    });
+    if target.is_empty() || has_scheme || target.starts_with('/') {
+        return None;
+    }
+    // Slugs never contain dots, so anything else with an extension is a file
+    let slug = target.strip_suffix(".md").unwrap_or(target);
+    match Path::new(slug).extension() {
+        Some(_) => None,
+        None => Some(slug),
+    }
+}
+
+/// Route of an article in the frontend, relative to the router basename
+pub fn article_route(slug: &str) -> String {
+    format!("/article/{}", slug)
+}
+
+/// Renders article Markdown to HTML at build time.
+///
+/// Wiki links and internal Markdown links are rewritten into anchors to
+/// the article route, carrying a `data-article-slug` (and, for `#section`
+/// links, `data-article-section`) attribute the frontend uses for router
+/// navigation. Links to unknown articles get the `broken-link` class.
+/// Headings get the ids from [`heading_anchors`]. Images that were
+/// published by [`AssetPublisher`](crate::assets::AssetPublisher) point at
+/// the published copy, with a `srcset` of its resized variants.
+///
+/// Raw HTML embedded in Markdown is passed through an allowlist sanitizer:
+/// only known-safe tags and attributes are kept, and URLs are limited to
+/// safe schemes, so the frontend can inject the result without further
+/// escaping.
+pub struct HtmlRenderer {
+    wiki_regex: Regex,
+    sanitizer: ammonia::Builder<'static>,
+}
+
+impl HtmlRenderer {
+    pub fn new() -> Self {
+        let mut sanitizer = ammonia::Builder::default();
+        // Keep the `language-*` class pulldown_cmark puts on fenced code blocks
+        sanitizer.add_tag_attributes("code", &["class"]);
+        sanitizer.add_tag_attributes("a", &["data-article-slug", "data-article-section"]);
+        sanitizer.add_tag_attributes("img", &["srcset", "loading"]);
+        for heading in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+            sanitizer.add_tag_attributes(heading, &["id"]);
+        }
+        sanitizer.add_allowed_classes("a", &["article-link", "broken-link"]);
+        Self {
+            wiki_regex: Regex::new(r"\[\[([^\]]+)\]\]").unwrap(),
+            sanitizer,
+        }
+    }
+
+    /// Render `markdown`, resolving article links against `known_slugs`
+    pub fn render(&self, markdown: &str, known_slugs: &HashSet<&str>) -> String {
+        self.render_with_assets(markdown, known_slugs, &HashMap::new())
+    }
+
+    /// Render `markdown`, also pointing images at their published copies.
+    /// `assets` is keyed by the image source as written.
+    pub fn render_with_assets(
+        &self,
+        markdown: &str,
+        known_slugs: &HashSet<&str>,
+        assets: &HashMap<&str, &PublishedAsset>,
+    ) -> String {
+        let mut events = Vec::new();
+        let mut pending_text = String::new();
+        let mut in_code_block = false;
+        let mut anchors = heading_anchors(markdown).into_iter();
+        // Published image being rendered, with its title and alt text so far
+        let mut open_image: Option<(&PublishedAsset, String, String)> = None;
+
+        for event in Parser::new(markdown) {
+            if let Some((_, _, alt_text)) = open_image.as_mut() {
+                match event {
+                    Event::Text(text) | Event::Code(text) => alt_text.push_str(&text),
+                    Event::End(TagEnd::Image) => {
+                        let (published, title, alt_text) = open_image.take().unwrap();
+                        events.push(Event::Html(image_tag(published, &title, &alt_text).into()));
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // pulldown_cmark splits text around brackets, so merge adjacent
+            // text events before looking for [[wiki links]]
+            if let Event::Text(text) = &event {
+                if !in_code_block {
+                    pending_text.push_str(text);
+                    continue;
+                }
+            }
+            self.flush_text(&mut pending_text, &mut events, known_slugs);
+
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => {
+                    in_code_block = true;
+                    events.push(event);
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    in_code_block = false;
+                    events.push(event);
+                }
+                Event::Start(Tag::Heading { level, classes, attrs, .. }) => {
+                    let id = anchors.next().map(Into::into);
+                    events.push(Event::Start(Tag::Heading { level, id, classes, attrs }));
+                }
+                // The matching End(Link) is rendered as `</a>` either way.
+                // Same-page `#fragment` links are left alone.
+                Event::Start(Tag::Link { dest_url, .. })
+                    if article_link_slug(split_section(&dest_url).0).is_some() =>
+                {
+                    let (target, section) = split_section(&dest_url);
+                    let slug = article_link_slug(target).unwrap_or(target);
+                    events.push(Event::Html(self.anchor_open(slug, section, known_slugs).into()));
+                }
+                Event::Start(Tag::Image { dest_url, title, .. }) if assets.contains_key(dest_url.as_ref()) => {
+                    open_image = Some((assets[dest_url.as_ref()], title.to_string(), String::new()));
+                }
+                other => events.push(other),
+            }
+        }
+        self.flush_text(&mut pending_text, &mut events, known_slugs);
+
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, events.into_iter());
+        self.sanitizer.clean(&html_output).to_string()
+    }
+
+    /// Emit buffered text, turning [[wiki links]] into anchors
+    fn flush_text<'a>(&self, text: &mut String, events: &mut Vec<Event<'a>>, known_slugs: &HashSet<&str>) {
+        if text.is_empty() {
+            return;
+        }
+
+        let mut last = 0;
+        for cap in self.wiki_regex.captures_iter(text) {
+            let full_match = cap.get(0).unwrap();
+            let inner = cap.get(1).unwrap().as_str();
+            let wiki_link = WikiLink::parse(inner);
+            if full_match.start() > last {
+                events.push(Event::Text(text[last..full_match.start()].to_string().into()));
+            }
+            let anchor = self.anchor_open(&slugify(wiki_link.target), wiki_link.section, known_slugs);
+            events.push(Event::Html(anchor.into()));
+            let display = wiki_link.label.unwrap_or(inner.trim());
+            events.push(Event::Text(display.to_string().into()));
+            events.push(Event::Html("</a>".into()));
+            last = full_match.end();
+        }
+        if last < text.len() {
+            events.push(Event::Text(text[last..].to_string().into()));
+        }
+        text.clear();
+    }
+
+    fn anchor_open(&self, slug: &str, section: Option<&str>, known_slugs: &HashSet<&str>) -> String {
+        let known = known_slugs.contains(slug);
+        let slug = escape_attribute(slug);
+        let (href, section_attribute) = match section {
+            Some(section) => {
+                let anchor = escape_attribute(&heading_anchor(section));
+                (
+                    format!("{}#{}", article_route(&slug), anchor),
+                    format!(" data-article-section=\"{}\"", anchor),
+                )
+            }
+            None => (article_route(&slug), String::new()),
+        };
+        if known {
+            format!(
+                "<a href=\"{}\" class=\"article-link\" data-article-slug=\"{}\"{}>",
+                href, slug, section_attribute
+            )
+        } else {
+            format!(
+                "<a href=\"{}\" class=\"article-link broken-link\" data-article-slug=\"{}\"{} title=\"Article not found\">",
+                href, slug, section_attribute
+            )
+        }
+    }
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `<img>` for a published image, with its size so the page doesn't
+/// reflow while it loads
+fn image_tag(published: &PublishedAsset, title: &str, alt_text: &str) -> String {
+    let mut tag = format!(
+        "<img src=\"{}\" alt=\"{}\"",
+        escape_attribute(&published.url),
+        escape_attribute(alt_text)
+    );
+    if !title.is_empty() {
+        tag.push_str(&format!(" title=\"{}\"", escape_attribute(title)));
+    }
+    if let Some(srcset) = published.srcset() {
+        tag.push_str(&format!(" srcset=\"{}\"", escape_attribute(&srcset)));
+    }
+    if let (Some(width), Some(height)) = (published.width, published.height) {
+        tag.push_str(&format!(" width=\"{}\" height=\"{}\"", width, height));
+    }
+    tag.push_str(" loading=\"lazy\">");
+    tag
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Article processing pipeline shared by the CLI tools:
+/// load → parse → link → render → graph → validate
+pub struct ArticlePipeline {
+    articles_dir: PathBuf,
+    link_extractor: LinkExtractor,
+    html_renderer: HtmlRenderer,
+    cache: BuildCache,
+    strict_front_matter: bool,
+}
+
+/// An article together with the hash of the source file it was parsed from
+#[derive(Debug, Clone)]
+pub struct ParsedArticle {
+    pub article: ProcessedArticle,
+    pub content_hash: String,
+    /// True when the article was taken from the build cache instead of being parsed
+    pub from_cache: bool,
+}
+
+impl ArticlePipeline {
+    /// Create a pipeline reading Markdown files from `articles_dir`
+    pub fn new(articles_dir: impl Into<PathBuf>) -> Result<Self> {
+        Ok(Self {
+            articles_dir: articles_dir.into(),
+            link_extractor: LinkExtractor::new()?,
+            html_renderer: HtmlRenderer::new(),
+            cache: BuildCache::default(),
+            strict_front_matter: false,
+        })
+    }
+
+    /// Reuse previously parsed articles whose source content hasn't changed
+    pub fn set_cache(&mut self, cache: BuildCache) {
+        self.cache = cache;
+    }
+
+    /// Reject articles whose front matter fails
+    /// [`FrontMatterParser::check_strict`] instead of parsing it leniently
+    pub fn set_strict_front_matter(&mut self, strict: bool) {
+        self.strict_front_matter = strict;
+    }
+
+    pub fn articles_dir(&self) -> &Path {
+        &self.articles_dir
+    }
+
+    /// Load: collect all Markdown files under the articles directory,
+    /// sorted by path so output order doesn't depend on the file system
+    pub fn collect_article_paths(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.articles_dir)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| is_markdown_file(e.path()))
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Parse: read a single file, parse and validate its front matter and extract links
+    pub fn parse_article(&self, file_path: &Path) -> Result<ProcessedArticle> {
+        self.parse_source(file_path).map(|parsed| parsed.article)
+    }
+
+    /// Parse a single file, or return the cached article when the file's
+    /// content hash matches the build cache
+    pub fn parse_source(&self, file_path: &Path) -> Result<ParsedArticle> {
+        // Read file content
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {:?}", file_path))?;
+        let file_path_str = file_path.to_string_lossy().to_string();
+        let content_hash = content_hash(&content);
+
+        // Checked before the cache so strictness doesn't depend on how the
+        // cached entry was parsed
+        if self.strict_front_matter {
+            FrontMatterParser::check_strict(&content)
+                .with_context(|| format!("Invalid front matter in: {:?}", file_path))?;
+        }
+
+        if let Some(article) = self.cache.lookup(&file_path_str, &content_hash) {
+            return Ok(ParsedArticle {
+                article: article.clone(),
+                content_hash,
+                from_cache: true,
+            });
+        }
+
+        // Parse front matter
+        let (metadata, markdown_content, body_offset) = FrontMatterParser::parse_with_body_offset(&content)
+            .with_context(|| format!("Failed to parse front matter in: {:?}", file_path))?;
+
+        // Validate metadata
+        FrontMatterParser::validate_metadata(&metadata)
+            .with_context(|| format!("Invalid metadata in: {:?}", file_path))?;
+
+        // Extract links and image assets from content, with line numbers
+        // relative to the whole file
+        let MarkdownReferences { links: mut outbound_links, mut assets, mut external_links } = self.link_extractor.extract(&markdown_content);
+        outbound_links.iter_mut().for_each(|link| link.line += body_offset);
+        assets.iter_mut().for_each(|asset| asset.line += body_offset);
+        external_links.iter_mut().for_each(|link| link.line += body_offset);
+
+        let article = ProcessedArticle {
+            slug: generate_slug(file_path),
+            title: metadata.title.clone(),
+            content: markdown_content,
+            html: String::new(), // Filled in by render_articles
+            date_sources: DateSources::from_metadata(&metadata),
+            metadata,
+            has_front_matter: body_offset > 0,
+            file_path: file_path_str,
+            outbound_links,
+            assets,
+            external_links,
+            inbound_count: 0, // Filled in by calculate_inbound_counts
+            processed_at: Utc::now().to_rfc3339(),
+        };
+
+        Ok(ParsedArticle {
+            article,
+            content_hash,
+            from_cache: false,
+        })
+    }
+
+    /// Parse several files, returning one result per path in the same order
+    pub fn parse_articles(&self, paths: &[PathBuf]) -> Vec<Result<ParsedArticle>> {
+        paths.iter().map(|path| self.parse_source(path)).collect()
+    }
+
+    /// Parse several files concurrently. `collect` on an indexed parallel
+    /// iterator keeps the input order, so the result is identical to
+    /// `parse_articles` regardless of thread scheduling.
+    pub fn parse_articles_parallel(&self, paths: &[PathBuf]) -> Vec<Result<ParsedArticle>> {
+        paths.par_iter().map(|path| self.parse_source(path)).collect()
+    }
+
+    /// Load and parse every article, failing on the first invalid file
+    pub fn load_articles(&self) -> Result<Vec<ProcessedArticle>> {
+        self.parse_articles(&self.collect_article_paths())
+            .into_iter()
+            .map(|parsed| parsed.map(|p| p.article))
+            .collect()
+    }
+
+    /// Render: produce sanitized HTML for every article. Runs after all
+    /// articles are known so links to missing articles can be marked, and
+    /// after images were published so they point at the copies.
+    pub fn render_articles(&self, articles: &mut [ProcessedArticle]) {
+        let slugs: Vec<String> = articles.iter().map(|a| a.slug.clone()).collect();
+        let known_slugs: HashSet<&str> = slugs.iter().map(String::as_str).collect();
+        for article in articles.iter_mut() {
+            let assets: HashMap<&str, &PublishedAsset> = article
+                .assets
+                .iter()
+                .filter_map(|asset| Some((asset.path.as_str(), asset.published.as_ref()?)))
+                .collect();
+            article.html = self.html_renderer.render_with_assets(&article.content, &known_slugs, &assets);
+        }
+    }
+
+    /// Drop drafts and articles scheduled after `now`, returning how many
+    /// were removed. Runs before linking so links to them count as missing.
+    pub fn remove_unpublished(articles: &mut Vec<ProcessedArticle>, now: DateTime<Utc>) -> usize {
+        let before = articles.len();
+        articles.retain(|article| article.metadata.is_published(now));
+        before - articles.len()
+    }
+
+    /// Link: count resolvable inbound links for every article
+    pub fn calculate_inbound_counts(articles: &mut [ProcessedArticle]) {
+        let existing_slugs: HashSet<String> = articles
+            .iter()
+            .map(|article| article.slug.clone())
+            .collect();
+
+        let mut inbound_counts: HashMap<String, usize> = HashMap::new();
+        for article in articles.iter() {
+            for link in &article.outbound_links {
+                if existing_slugs.contains(&link.target_slug) {
+                    *inbound_counts.entry(link.target_slug.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        for article in articles.iter_mut() {
+            article.inbound_count = inbound_counts.get(&article.slug).copied().unwrap_or(0);
+        }
+    }
+
+    /// Graph: build the link graph, merging duplicate links and marking bidirectional pairs
+    pub fn build_link_graph(articles: &[ProcessedArticle]) -> LinkGraphData {
+        let mut graph = BTreeMap::new();
+        let article_slugs: HashSet<_> = articles.iter().map(|a| &a.slug).collect();
+        
+        // First pass: create nodes and direct connections
+        for article in articles {
+            // Count links to each target (for duplicate detection)
+            let mut connection_counts: BTreeMap<String, usize> = BTreeMap::new();
+            for link in &article.outbound_links {
+                if article_slugs.contains(&link.target_slug) {
+                    *connection_counts.entry(link.target_slug.clone()).or_insert(0) += 1;
+                }
+            }
+            
+            let connections = connection_counts
+                .into_iter()
+                .map(|(target, link_count)| GraphConnection {
+                    target,
+                    connection_type: ConnectionType::DirectLink,
+                    bidirectional: false,
+                    link_count,
+                })
+                .collect();
+            
+            graph.insert(article.slug.clone(), GraphNode {
+                connections,
+                inbound_count: article.inbound_count,
+                outbound_count: article.outbound_links.len(),
+            });
+        }
+        
+        // Second pass: detect bidirectional links
+        let mut bidirectional_pairs = HashSet::new();
+        for (source_slug, source_node) in &graph {
+            for connection in &source_node.connections {
+                let target_slug = &connection.target;
+                let has_backlink = graph
+                    .get(target_slug)
+                    .is_some_and(|target_node| target_node.connections.iter().any(|c| c.target == *source_slug));
+                
+                if has_backlink {
+                    // Create a canonical pair (smaller slug first) to avoid duplicates
+                    let pair = if source_slug < target_slug {
+                        (source_slug.clone(), target_slug.clone())
+                    } else {
+                        (target_slug.clone(), source_slug.clone())
+                    };
+                    bidirectional_pairs.insert(pair);
+                }
+            }
+        }
+        
+        // Third pass: update bidirectional connections in both nodes
+        for (slug1, slug2) in &bidirectional_pairs {
+            for (from, to) in [(slug1, slug2), (slug2, slug1)] {
+                if let Some(node) = graph.get_mut(from) {
+                    for connection in &mut node.connections {
+                        if connection.target == *to {
+                            connection.connection_type = ConnectionType::Bidirectional;
+                            connection.bidirectional = true;
+                        }
+                    }
+                }
+            }
+        }
+        
+        let total_connections = graph.values()
+            .map(|node| node.connections.len())
+            .sum();
+        
+        let direct_links = graph.values()
+            .flat_map(|node| &node.connections)
+            .filter(|conn| !conn.bidirectional)
+            .count();
+        
+        LinkGraphData {
+            schema_version: SCHEMA_VERSION,
+            graph,
+            generated_at: Utc::now().to_rfc3339(),
+            total_connections,
+            bidirectional_pairs: bidirectional_pairs.len(),
+            direct_links,
+        }
+    }
+
+    /// Assemble the articles.json payload
+    pub fn build_articles_data(articles: &[ProcessedArticle]) -> ArticlesData {
+        ArticlesData {
+            schema_version: SCHEMA_VERSION,
+            articles: articles.to_vec(),
+            generated_at: Utc::now().to_rfc3339(),
+            total_count: articles.len(),
+            home_articles: articles
+                .iter()
+                .filter(|a| a.metadata.home_display)
+                .map(|a| a.slug.clone())
+                .collect(),
+        }
+    }
+
+    /// Assemble the article-index.json payload (no article bodies)
+    pub fn build_article_index(articles: &[ProcessedArticle]) -> ArticleIndex {
+        ArticleIndex {
+            schema_version: SCHEMA_VERSION,
+            articles: articles.iter().map(LightweightArticle::from).collect(),
+            generated_at: Utc::now().to_rfc3339(),
+            total_count: articles.len(),
+            home_articles: articles
+                .iter()
+                .filter(|a| a.metadata.home_display)
+                .map(|a| a.slug.clone())
+                .collect(),
+        }
+    }
+
+    /// Assemble the per-article content file payload
+    pub fn build_article_content(article: &ProcessedArticle) -> ArticleContentData {
+        ArticleContentData {
+            schema_version: SCHEMA_VERSION,
+            article: article.clone(),
+        }
+    }
+
+    /// Validate: check links and references across all articles
+    pub fn validate(&self, articles: &[ProcessedArticle]) -> Result<ValidationReport> {
+        self.validate_with_options(articles, ValidationOptions::default())
+    }
+
+    pub fn validate_with_options(&self, articles: &[ProcessedArticle], options: ValidationOptions) -> Result<ValidationReport> {
+        let article_refs: Vec<ProcessedArticleRef> = articles.iter().map(ProcessedArticleRef::from).collect();
+        LinkValidator::with_options(&article_refs, options)
+            .with_asset_issues(find_asset_issues(&self.articles_dir, articles))
+            .validate_all()
+    }
+}
+
+/// Whether a path looks like an article source file
+pub fn is_markdown_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md")
+}
+
+/// Bump whenever parsing changes what is produced for the same source file,
+/// so stale cache entries are discarded
+const BUILD_CACHE_VERSION: u32 = 12;
+
+/// Hex-encoded SHA-256 of an article source file
+pub fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Incremental build cache: parsed articles keyed by source path and
+/// content hash. Reusing an entry keeps its `processed_at`, so unchanged
+/// articles produce byte-identical output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildCache {
+    cache_version: u32,
+    schema_version: u32,
+    entries: BTreeMap<String, BuildCacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildCacheEntry {
+    pub content_hash: String,
+    pub article: ProcessedArticle,
+}
+
+impl Default for BuildCache {
+    fn default() -> Self {
+        Self {
+            cache_version: BUILD_CACHE_VERSION,
+            schema_version: SCHEMA_VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl BuildCache {
+    /// Build a cache from the articles of the current run
+    pub fn from_parsed<'a>(parsed: impl IntoIterator<Item = &'a ParsedArticle>) -> Self {
+        let mut cache = Self::default();
+        for parsed in parsed {
+            cache.entries.insert(parsed.article.file_path.clone(), BuildCacheEntry {
+                content_hash: parsed.content_hash.clone(),
+                article: parsed.article.clone(),
+            });
+        }
+        cache
+    }
+
+    /// Load a cache file. A missing file yields an empty cache; a cache
+    /// written by an incompatible version is discarded.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read build cache from {:?}", path))?;
+        let cache: Self = serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse build cache {:?}", path))?;
+
+        if cache.cache_version != BUILD_CACHE_VERSION || cache.schema_version != SCHEMA_VERSION {
+            return Ok(Self::default());
+        }
+        Ok(cache)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self)
+            .context("Failed to serialize build cache")?;
+        write_atomically(path, &json)
+            .with_context(|| format!("Failed to write build cache to {:?}", path))
+    }
+
+    /// Cached article for `file_path`, if its source still hashes to `content_hash`
+    pub fn lookup(&self, file_path: &str, content_hash: &str) -> Option<&ProcessedArticle> {
+        self.entries
+            .get(file_path)
+            .filter(|entry| entry.content_hash == content_hash)
+            .map(|entry| &entry.article)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Write `contents` to a temporary file next to `path` and rename it into
+/// place, so readers never observe a half-written file
+pub fn write_atomically(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+    std::fs::write(&tmp_path, contents)
+        .with_context(|| format!("Failed to write temporary file {:?}", tmp_path))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to move {:?} into place", tmp_path))?;
+    Ok(())
+}
+
+/// Write a generated JSON file unless its content is unchanged.
+///
+/// `generated_at` is ignored when comparing with the existing file, so a
+/// rebuild with identical inputs leaves the file (and its timestamp)
+/// untouched. Returns whether the file was written.
+pub fn write_json_output<T: Serialize>(path: &Path, payload: &T) -> Result<bool> {
+    let json = serde_json::to_string_pretty(payload)
+        .with_context(|| format!("Failed to serialize {:?}", path))?;
+
+    if let Ok(existing) = std::fs::read_to_string(path) {
+        let unchanged = match (
+            serde_json::from_str::<serde_json::Value>(&existing),
+            serde_json::from_str::<serde_json::Value>(&json),
+        ) {
+            (Ok(mut old), Ok(mut new)) => {
+                for value in [&mut old, &mut new] {
+                    if let Some(object) = value.as_object_mut() {
+                        object.remove("generated_at");
+                    }
+                }
+                old == new
+            }
+            _ => false,
+        };
+        if unchanged {
+            return Ok(false);
+        }
+    }
+
+    write_atomically(path, &json)?;
+    Ok(true)
+}
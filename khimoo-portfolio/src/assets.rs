@@ -0,0 +1,384 @@
+//! Local images referenced from articles.
+//!
+//! Image paths are resolved relative to the article that references them.
+//! [`AssetPublisher`] copies each image into the data output under a
+//! content-hashed file name, so it can be cached forever, and writes
+//! downscaled variants for responsive `srcset`s. Files outside the
+//! articles directory are never published, since the output is deployed.
+//! [`find_asset_issues`] reports references to files that don't exist or
+//! that leave the articles directory for `validate-links`.
+
+use crate::article_processing::{
+    slugify, write_atomically, AssetVariant, ProcessedArticle, PublishedAsset, ASSET_DIR, ASSET_URL_PREFIX,
+};
+use anyhow::{Context, Result};
+use image::imageops::FilterType;
+use image::ImageFormat;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+/// Widths of the downscaled variants written for raster images
+pub const DEFAULT_VARIANT_WIDTHS: &[u32] = &[480, 960];
+
+/// Hex digits of the content hash kept in published file names
+const ASSET_HASH_LENGTH: usize = 12;
+
+/// Formats that are resized. GIFs are only copied, since resizing would
+/// drop their animation.
+const RESIZABLE_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP];
+
+/// Whether an image source points at a file next to the articles, as
+/// opposed to a remote URL, a `data:` URI or a site-absolute path
+pub fn is_local_asset(path: &str) -> bool {
+    !path.is_empty()
+        && !path.contains("://")
+        && !path.starts_with("//")
+        && !path.starts_with('/')
+        && !path.starts_with("data:")
+}
+
+/// Where a local image source points, see [`locate_asset`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssetLocation {
+    /// Inside the articles directory. The file may not exist.
+    Inside(PathBuf),
+    /// Outside the articles directory, through `..` segments or a symlink
+    Outside(PathBuf),
+}
+
+/// File an image source refers to, relative to the directory of
+/// `article_file`, and whether it stays inside `articles_root`. Returns
+/// `None` for sources that aren't local.
+pub fn locate_asset(articles_root: &Path, article_file: &Path, path: &str) -> Option<AssetLocation> {
+    if !is_local_asset(path) {
+        return None;
+    }
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let article_dir = article_file.parent().unwrap_or_else(|| Path::new(""));
+    let resolved = article_dir.join(path);
+    if real_path(&resolved).starts_with(real_path(articles_root)) {
+        Some(AssetLocation::Inside(resolved))
+    } else {
+        Some(AssetLocation::Outside(resolved))
+    }
+}
+
+/// File an image source refers to, relative to the directory of
+/// `article_file`. Returns `None` for sources that aren't local or that
+/// leave `articles_root`.
+pub fn resolve_asset_path(articles_root: &Path, article_file: &Path, path: &str) -> Option<PathBuf> {
+    match locate_asset(articles_root, article_file, path)? {
+        AssetLocation::Inside(resolved) => Some(resolved),
+        AssetLocation::Outside(_) => None,
+    }
+}
+
+/// Absolute `path` with symlinks resolved. Components that don't exist are
+/// appended as written, with `..` applied lexically, so missing files can
+/// be located too.
+pub(crate) fn real_path(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    if let Ok(canonical) = absolute.canonicalize() {
+        return canonical;
+    }
+    let mut real = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                real.pop();
+            }
+            component => {
+                real.push(component);
+                if let Ok(canonical) = real.canonicalize() {
+                    real = canonical;
+                }
+            }
+        }
+    }
+    real
+}
+
+/// Why a local image reference can't be published
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetIssueKind {
+    /// The file doesn't exist
+    Missing,
+    /// The file is outside the articles directory
+    OutsideArticles,
+}
+
+/// A local image reference that can't be published
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetIssue {
+    pub kind: AssetIssueKind,
+    pub source_article: String,
+    pub file_path: String,
+    /// Image source as written
+    pub reference: String,
+    pub resolved_path: PathBuf,
+    /// Position of the Markdown image; `None` for `author_image`
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+/// Local images in Markdown and `author_image` front matter that don't
+/// exist or that are outside `articles_root`
+pub fn find_asset_issues(articles_root: &Path, articles: &[ProcessedArticle]) -> Vec<AssetIssue> {
+    let mut issues = Vec::new();
+    for article in articles {
+        let article_file = Path::new(&article.file_path);
+        let references = article
+            .assets
+            .iter()
+            .map(|asset| (asset.path.as_str(), Some(asset.line), Some(asset.column)))
+            .chain(article.metadata.author_image.as_deref().map(|path| (path, None, None)));
+
+        for (reference, line, column) in references {
+            let (kind, resolved_path) = match locate_asset(articles_root, article_file, reference) {
+                None => continue,
+                Some(AssetLocation::Inside(resolved)) if resolved.is_file() => continue,
+                Some(AssetLocation::Inside(resolved)) => (AssetIssueKind::Missing, resolved),
+                Some(AssetLocation::Outside(resolved)) => (AssetIssueKind::OutsideArticles, resolved),
+            };
+            issues.push(AssetIssue {
+                kind,
+                source_article: article.slug.clone(),
+                file_path: article.file_path.clone(),
+                reference: reference.to_string(),
+                resolved_path,
+                line,
+                column,
+            });
+        }
+    }
+    issues
+}
+
+/// Outcome of [`AssetPublisher::publish_articles`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetPublishSummary {
+    /// Distinct source images published
+    pub images: usize,
+    /// Files written, counting variants. Files already present with the
+    /// same content hash are left alone.
+    pub written: usize,
+    /// Local references to files that don't exist
+    pub missing: usize,
+    /// Local references to files outside the articles directory, which
+    /// are never published
+    pub outside_articles: usize,
+    /// Files left behind by images that are no longer referenced
+    pub removed: usize,
+}
+
+/// Copies local images into `<output_dir>/assets` and points the article
+/// references at the copies
+pub struct AssetPublisher {
+    articles_root: PathBuf,
+    asset_dir: PathBuf,
+    variant_widths: Vec<u32>,
+}
+
+/// Bookkeeping for one [`AssetPublisher::publish_articles`] run
+#[derive(Default)]
+struct PublishRun {
+    published: HashMap<PathBuf, PublishedAsset>,
+    file_names: HashSet<String>,
+    summary: AssetPublishSummary,
+}
+
+impl AssetPublisher {
+    /// Publish images from `articles_root` into `output_dir`
+    pub fn new(articles_root: &Path, output_dir: &Path) -> Self {
+        Self {
+            articles_root: articles_root.to_path_buf(),
+            asset_dir: output_dir.join(ASSET_DIR),
+            variant_widths: DEFAULT_VARIANT_WIDTHS.to_vec(),
+        }
+    }
+
+    /// Widths of the downscaled variants. Only widths smaller than the
+    /// original are written; an empty list disables resizing.
+    pub fn with_variant_widths(mut self, mut widths: Vec<u32>) -> Self {
+        widths.sort_unstable();
+        widths.dedup();
+        self.variant_widths = widths;
+        self
+    }
+
+    pub fn asset_dir(&self) -> &Path {
+        &self.asset_dir
+    }
+
+    /// Publish every local image referenced by `articles`, set
+    /// `published` on their asset references and rewrite local
+    /// `author_image` paths to the published URL. Missing files and files
+    /// outside the articles directory are skipped and counted;
+    /// `validate-links` reports them.
+    pub fn publish_articles(&self, articles: &mut [ProcessedArticle]) -> Result<AssetPublishSummary> {
+        std::fs::create_dir_all(&self.asset_dir)
+            .with_context(|| format!("Failed to create {:?}", self.asset_dir))?;
+
+        let mut run = PublishRun::default();
+        for article in articles.iter_mut() {
+            let article_file = PathBuf::from(&article.file_path);
+            for asset in article.assets.iter_mut() {
+                asset.published = self.publish_reference(&mut run, &article_file, &asset.path)
+                    .with_context(|| format!("Failed to publish image '{}' of {}", asset.path, article.file_path))?;
+            }
+            if let Some(author_image) = article.metadata.author_image.as_mut() {
+                let published = self.publish_reference(&mut run, &article_file, author_image)
+                    .with_context(|| format!("Failed to publish author_image of {}", article.file_path))?;
+                if let Some(published) = published {
+                    *author_image = published.url;
+                }
+            }
+        }
+
+        run.summary.images = run.published.len();
+        run.summary.removed = self.remove_stale_files(&run.file_names)?;
+        Ok(run.summary)
+    }
+
+    fn publish_reference(&self, run: &mut PublishRun, article_file: &Path, reference: &str) -> Result<Option<PublishedAsset>> {
+        let source = match locate_asset(&self.articles_root, article_file, reference) {
+            None => return Ok(None),
+            Some(AssetLocation::Outside(_)) => {
+                run.summary.outside_articles += 1;
+                return Ok(None);
+            }
+            Some(AssetLocation::Inside(source)) => source,
+        };
+        if !source.is_file() {
+            run.summary.missing += 1;
+            return Ok(None);
+        }
+
+        // Several articles may share an image under different relative paths
+        let key = source.canonicalize().unwrap_or(source);
+        if let Some(published) = run.published.get(&key) {
+            return Ok(Some(published.clone()));
+        }
+        let published = self.publish_file(run, &key)?;
+        run.published.insert(key, published.clone());
+        Ok(Some(published))
+    }
+
+    fn publish_file(&self, run: &mut PublishRun, source: &Path) -> Result<PublishedAsset> {
+        let bytes = std::fs::read(source)
+            .with_context(|| format!("Failed to read {:?}", source))?;
+        let hash: String = Sha256::digest(&bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let hash = &hash[..ASSET_HASH_LENGTH];
+
+        let stem = source.file_stem().map(|stem| slugify(&stem.to_string_lossy())).unwrap_or_default();
+        let stem = if stem.is_empty() { "image".to_string() } else { stem };
+        let extension = source.extension().map(|ext| ext.to_string_lossy().to_lowercase()).unwrap_or_default();
+        let file_name = |suffix: &str| match extension.as_str() {
+            "" => format!("{}.{}{}", stem, hash, suffix),
+            extension => format!("{}.{}{}.{}", stem, hash, suffix, extension),
+        };
+
+        let original = file_name("");
+        self.write_file(run, &original, || Ok(bytes.clone()))?;
+
+        let as_is = PublishedAsset {
+            url: format!("{}{}", ASSET_URL_PREFIX, original),
+            width: None,
+            height: None,
+            variants: Vec::new(),
+        };
+        let Some(format) = ImageFormat::from_extension(&extension).filter(|format| format.reading_enabled()) else {
+            return Ok(as_is);
+        };
+        // A file that doesn't decode as its extension says is still
+        // published as is, like the formats that can't be decoded at all
+        let unreadable = |e: image::ImageError| {
+            eprintln!("⚠️  Publishing {} without dimensions, it can't be read as {:?}: {}", source.display(), format, e);
+        };
+        let (width, height) = match image::ImageReader::with_format(Cursor::new(&bytes), format).into_dimensions() {
+            Ok(dimensions) => dimensions,
+            Err(e) => {
+                unreadable(e);
+                return Ok(as_is);
+            }
+        };
+
+        let mut variants = Vec::new();
+        if RESIZABLE_FORMATS.contains(&format) {
+            let variant_names: Vec<(u32, String)> = self.variant_widths.iter()
+                .filter(|w| **w < width)
+                .map(|w| (*w, file_name(&format!(".w{}", w))))
+                .collect();
+            // Decoded at most once, and only when a variant is missing. A
+            // truncated file can have a valid header, so this can still fail.
+            let decoded = if variant_names.iter().any(|(_, name)| !self.asset_dir.join(name).exists()) {
+                match image::load_from_memory_with_format(&bytes, format) {
+                    Ok(decoded) => Some(decoded),
+                    Err(e) => {
+                        unreadable(e);
+                        return Ok(as_is);
+                    }
+                }
+            } else {
+                None
+            };
+            for (variant_width, variant_name) in variant_names {
+                self.write_file(run, &variant_name, || {
+                    let decoded = decoded.as_ref().context("Image was not decoded")?;
+                    let resized = decoded.resize(variant_width, u32::MAX, FilterType::Lanczos3);
+                    let mut encoded = Cursor::new(Vec::new());
+                    resized.write_to(&mut encoded, format)
+                        .with_context(|| format!("Failed to encode {}px variant of {:?}", variant_width, source))?;
+                    Ok(encoded.into_inner())
+                })?;
+                variants.push(AssetVariant {
+                    url: format!("{}{}", ASSET_URL_PREFIX, variant_name),
+                    width: variant_width,
+                });
+            }
+        }
+
+        Ok(PublishedAsset {
+            width: Some(width),
+            height: Some(height),
+            variants,
+            ..as_is
+        })
+    }
+
+    /// Write a published file unless it already exists. Names include the
+    /// content hash, so an existing file never needs to be replaced.
+    fn write_file(&self, run: &mut PublishRun, file_name: &str, contents: impl FnOnce() -> Result<Vec<u8>>) -> Result<()> {
+        run.file_names.insert(file_name.to_string());
+        let path = self.asset_dir.join(file_name);
+        if path.exists() {
+            return Ok(());
+        }
+        write_atomically(&path, contents()?)?;
+        run.summary.written += 1;
+        Ok(())
+    }
+
+    /// Remove files that weren't produced by this run
+    fn remove_stale_files(&self, current: &HashSet<String>) -> Result<usize> {
+        let mut removed = 0;
+        for entry in std::fs::read_dir(&self.asset_dir)? {
+            let path = entry?.path();
+            let is_current = path
+                .file_name()
+                .is_some_and(|name| current.contains(name.to_string_lossy().as_ref()));
+            if path.is_file() && !is_current {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove stale asset {:?}", path))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
@@ -1,13 +1,20 @@
 use khimoo_portfolio::article_processing::{
-    FrontMatterParser, ArticleMetadata, LinkExtractor, ExtractedLink
+    ArticlePipeline, BuildCache, LinkGraphData, ParsedArticle, ProcessedArticle, LinkType,
+    is_markdown_file, article_content_path, write_json_output, ARTICLE_CONTENT_DIR
 };
+use khimoo_portfolio::assets::{AssetPublisher, DEFAULT_VARIANT_WIDTHS};
+use khimoo_portfolio::git_dates::GitDates;
 use anyhow::{Context, Result};
+use chrono::Utc;
 use clap::Parser;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
-use chrono::Utc;
+use std::sync::mpsc;
+use std::time::Duration;
+use notify::{RecursiveMode, Watcher};
+
+/// How long to wait for further file events before rebuilding in watch mode
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
 
 #[derive(Parser)]
 #[command(name = "process-articles")]
@@ -28,53 +35,36 @@ struct Args {
     /// Enable parallel processing
     #[arg(short, long)]
     parallel: bool,
-}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProcessedArticle {
-    pub slug: String,
-    pub title: String,
-    pub content: String,
-    pub metadata: ArticleMetadata,
-    pub file_path: String,
-    pub outbound_links: Vec<ExtractedLink>,
-    pub inbound_count: usize,
-
-    pub processed_at: String,
-}
+    /// Watch the articles directory and reprocess changed files
+    #[arg(short, long)]
+    watch: bool,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ArticlesData {
-    pub articles: Vec<ProcessedArticle>,
-    pub generated_at: String,
-    pub total_count: usize,
-    pub home_articles: Vec<String>,
-}
+    /// Build cache used to skip articles whose content hasn't changed
+    #[arg(long, default_value = ".article-cache.json")]
+    cache_file: PathBuf,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LinkGraphData {
-    pub graph: HashMap<String, GraphNode>,
-    pub generated_at: String,
-    pub total_connections: usize,
-}
+    /// Ignore and don't update the build cache
+    #[arg(long)]
+    no_cache: bool,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GraphNode {
-    pub connections: Vec<GraphConnection>,
-    pub inbound_count: usize,
-}
+    /// Fail on unknown front matter keys, malformed YAML or TOML and invalid
+    /// values instead of parsing leniently
+    #[arg(long)]
+    strict: bool,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GraphConnection {
-    pub target: String,
-    pub connection_type: ConnectionType,
-    pub bidirectional: bool,
-}
+    /// Also build articles marked `draft: true` or with a future `publish_at`
+    #[arg(long)]
+    include_drafts: bool,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ConnectionType {
-    DirectLink,
-    Bidirectional,
+    /// Fill created_at/updated_at missing from front matter with the first
+    /// and last commit touching each article
+    #[arg(long)]
+    git_dates: bool,
+
+    /// Widths of the resized variants written for each image, e.g. 480,960
+    #[arg(long, value_delimiter = ',', default_values_t = DEFAULT_VARIANT_WIDTHS.to_vec())]
+    image_widths: Vec<u32>,
 }
 
 fn main() -> Result<()> {
@@ -84,11 +74,26 @@ fn main() -> Result<()> {
         println!("🔄 Processing articles from {:?}", args.articles_dir);
         println!("📁 Output directory: {:?}", args.output_dir);
         println!("⚡ Parallel processing: {}", args.parallel);
+        println!("👀 Watch mode: {}", args.watch);
+        if !args.no_cache {
+            println!("♻️  Build cache: {:?}", args.cache_file);
+        }
     }
     
-    let processor = ArticleProcessor::new(args.articles_dir, args.output_dir, args.verbose);
+    let mut processor = ArticleProcessor::new(args.articles_dir, args.output_dir, args.verbose)?
+        .with_image_widths(args.image_widths)
+        .with_strict_front_matter(args.strict)
+        .with_include_drafts(args.include_drafts);
+    if args.git_dates {
+        processor = processor.with_git_dates()?;
+    }
+    if !args.no_cache {
+        processor = processor.with_cache_file(args.cache_file)?;
+    }
     
-    if args.parallel {
+    if args.watch {
+        processor.watch()
+    } else if args.parallel {
         processor.process_all_articles_parallel()
     } else {
         processor.process_all_articles_sequential()
@@ -96,20 +101,77 @@ fn main() -> Result<()> {
 }
 
 pub struct ArticleProcessor {
-    articles_dir: PathBuf,
+    pipeline: ArticlePipeline,
+    assets: AssetPublisher,
+    git_dates: Option<GitDates>,
+    include_drafts: bool,
     output_dir: PathBuf,
+    cache_file: Option<PathBuf>,
     verbose: bool,
-    link_extractor: LinkExtractor,
 }
 
 impl ArticleProcessor {
-    pub fn new(articles_dir: PathBuf, output_dir: PathBuf, verbose: bool) -> Self {
-        Self {
-            articles_dir,
+    pub fn new(articles_dir: PathBuf, output_dir: PathBuf, verbose: bool) -> Result<Self> {
+        Ok(Self {
+            assets: AssetPublisher::new(&articles_dir, &output_dir),
+            pipeline: ArticlePipeline::new(articles_dir)?,
+            git_dates: None,
+            include_drafts: false,
             output_dir,
+            cache_file: None,
             verbose,
-            link_extractor: LinkExtractor::new().expect("Failed to create LinkExtractor"),
+        })
+    }
+
+    /// Widths of the resized image variants
+    pub fn with_image_widths(mut self, widths: Vec<u32>) -> Self {
+        self.assets = AssetPublisher::new(self.pipeline.articles_dir(), &self.output_dir).with_variant_widths(widths);
+        self
+    }
+
+    /// Reject articles whose front matter fails the strict check
+    pub fn with_strict_front_matter(mut self, strict: bool) -> Self {
+        self.pipeline.set_strict_front_matter(strict);
+        self
+    }
+
+    /// Keep drafts and scheduled articles in the generated data
+    pub fn with_include_drafts(mut self, include_drafts: bool) -> Self {
+        self.include_drafts = include_drafts;
+        self
+    }
+
+    /// Take missing dates from the git history of the articles directory.
+    /// The history is read once, so commits made while watching are only
+    /// picked up on the next run.
+    pub fn with_git_dates(mut self) -> Result<Self> {
+        let git_dates = GitDates::open(self.pipeline.articles_dir())
+            .context("Failed to read article dates from git")?;
+        if git_dates.is_shallow() {
+            eprintln!("⚠️  Shallow git clone: created_at dates reflect the oldest fetched commit");
         }
+        self.git_dates = Some(git_dates);
+        Ok(self)
+    }
+
+    /// Reuse unchanged articles from `cache_file` and update it after each build.
+    /// A cache that can't be read is ignored rather than failing the build.
+    pub fn with_cache_file(mut self, cache_file: PathBuf) -> Result<Self> {
+        let cache = match BuildCache::load(&cache_file) {
+            Ok(cache) => cache,
+            Err(e) => {
+                eprintln!("⚠️  Ignoring build cache: {:#}", e);
+                BuildCache::default()
+            }
+        };
+        
+        if self.verbose {
+            println!("♻️  Loaded {} cached articles", cache.len());
+        }
+        
+        self.pipeline.set_cache(cache);
+        self.cache_file = Some(cache_file);
+        Ok(self)
     }
 
     pub fn process_all_articles_sequential(&self) -> Result<()> {
@@ -121,23 +183,35 @@ impl ArticleProcessor {
         let articles = self.load_and_parse_articles()
             .context("Failed to load articles")?;
         
+        self.finish_processing(articles)
+    }
+
+    pub fn process_all_articles_parallel(&self) -> Result<()> {
+        // Create output directory
+        std::fs::create_dir_all(&self.output_dir)
+            .context("Failed to create output directory")?;
+        
+        // Load and parse all articles on the rayon thread pool
+        let articles = self.load_and_parse_articles_parallel()
+            .context("Failed to load articles")?;
+        
+        self.finish_processing(articles)
+    }
+
+    fn finish_processing(&self, parsed: Vec<ParsedArticle>) -> Result<()> {
         if self.verbose {
-            println!("📚 Found {} articles", articles.len());
+            println!("📚 Found {} articles", parsed.len());
         }
         
-        // Calculate inbound link counts
-        let articles_with_counts = self.calculate_inbound_counts(articles)
-            .context("Failed to calculate inbound counts")?;
+        let reused = parsed.iter().filter(|p| p.from_cache).count();
+        if reused > 0 {
+            println!("♻️  Reused {} unchanged articles from cache", reused);
+        }
         
-        // Build link graph
-        let link_graph = self.build_link_graph(&articles_with_counts)
-            .context("Failed to build link graph")?;
+        self.save_cache(&parsed)?;
         
-        // Write output files
-        self.write_articles_data(&articles_with_counts)
-            .context("Failed to write articles data")?;
-        self.write_link_graph_data(&link_graph)
-            .context("Failed to write link graph data")?;
+        let articles = parsed.into_iter().map(|p| p.article).collect();
+        let (articles_with_counts, link_graph) = self.generate_outputs(articles)?;
         
         println!("✅ Successfully processed {} articles", articles_with_counts.len());
         
@@ -147,247 +221,321 @@ impl ArticleProcessor {
         Ok(())
     }
 
-    pub fn process_all_articles_parallel(&self) -> Result<()> {
-        // For now, fall back to sequential processing
-        // TODO: Implement parallel processing with rayon
-        if self.verbose {
-            println!("⚠️  Parallel processing not yet implemented, using sequential");
-        }
-        self.process_all_articles_sequential()
-    }
-
-    fn load_and_parse_articles(&self) -> Result<Vec<ProcessedArticle>> {
-        let mut articles = Vec::new();
+    /// Watch the articles directory and regenerate the output files whenever
+    /// a Markdown file changes. Only the changed files are re-parsed; inbound
+    /// counts and the link graph are always recomputed from the full set.
+    pub fn watch(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.output_dir)
+            .context("Failed to create output directory")?;
         
-        for entry in WalkDir::new(&self.articles_dir)
+        let mut articles: BTreeMap<PathBuf, ParsedArticle> = self.load_and_parse_articles()
+            .context("Failed to load articles")?
             .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().map_or(false, |ext| ext == "md"))
-        {
-            match self.process_article_file(entry.path()) {
-                Ok(article) => {
-                    if self.verbose {
-                        println!("✅ Processed: {} - '{}'", 
-                            entry.path().display(), 
-                            article.title
-                        );
-                    }
-                    articles.push(article);
-                }
-                Err(e) => {
-                    eprintln!("❌ Error processing {}: {}", entry.path().display(), e);
-                    return Err(e);
+            .map(|parsed| (PathBuf::from(&parsed.article.file_path), parsed))
+            .collect();
+        
+        self.save_cache(articles.values())?;
+        let (articles_with_counts, _) = self.generate_outputs(current_articles(&articles))?;
+        println!("✅ Successfully processed {} articles", articles_with_counts.len());
+        
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)
+            .context("Failed to create file watcher")?;
+        watcher.watch(self.pipeline.articles_dir(), RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {:?}", self.pipeline.articles_dir()))?;
+        
+        println!("👀 Watching {:?} for changes (Ctrl+C to stop)", self.pipeline.articles_dir());
+        
+        // Block until the first event, then collect everything that arrives
+        // within the debounce window so that a single save triggers one rebuild
+        while let Ok(first_event) = rx.recv() {
+            let mut changed_paths = BTreeSet::new();
+            self.collect_changed_paths(first_event, &mut changed_paths);
+            while let Ok(event) = rx.recv_timeout(WATCH_DEBOUNCE) {
+                self.collect_changed_paths(event, &mut changed_paths);
+            }
+            
+            if changed_paths.is_empty() {
+                continue;
+            }
+            
+            if !self.apply_changes(&mut articles, &changed_paths) {
+                continue;
+            }
+            
+            if let Err(e) = self.save_cache(articles.values()) {
+                eprintln!("⚠️  Failed to update build cache: {:#}", e);
+            }
+            
+            match self.generate_outputs(current_articles(&articles)) {
+                Ok((articles_with_counts, _)) => {
+                    println!("🔄 Reprocessed {} changed file(s), {} articles total",
+                        changed_paths.len(),
+                        articles_with_counts.len()
+                    );
                 }
+                Err(e) => eprintln!("❌ Failed to regenerate data: {:#}", e),
             }
         }
         
-        Ok(articles)
+        Ok(())
     }
 
-    fn process_article_file(&self, file_path: &Path) -> Result<ProcessedArticle> {
-        // Read file content
-        let content = std::fs::read_to_string(file_path)
-            .with_context(|| format!("Failed to read file: {:?}", file_path))?;
-
-        // Parse front matter
-        let (metadata, markdown_content) = FrontMatterParser::parse(&content)
-            .with_context(|| format!("Failed to parse front matter in: {:?}", file_path))?;
-
-        // Validate metadata
-        FrontMatterParser::validate_metadata(&metadata)
-            .with_context(|| format!("Invalid metadata in: {:?}", file_path))?;
-
-        // Extract links from content
-        let outbound_links = self.link_extractor.extract_links(&markdown_content);
+    /// Collect Markdown paths touched by a watcher event
+    fn collect_changed_paths(&self, event: notify::Result<notify::Event>, changed_paths: &mut BTreeSet<PathBuf>) {
+        match event {
+            Ok(event) => {
+                if event.kind.is_access() {
+                    return;
+                }
+                for path in event.paths {
+                    if is_markdown_file(&path) {
+                        changed_paths.insert(self.relative_to_articles_dir(&path));
+                    }
+                }
+            }
+            Err(e) => eprintln!("⚠️  Watch error: {}", e),
+        }
+    }
 
-        // Generate slug from file path
-        let slug = self.generate_slug(file_path);
+    /// Map an absolute path reported by the watcher back onto the
+    /// `articles_dir`-relative form used by `load_and_parse_articles`
+    fn relative_to_articles_dir(&self, path: &Path) -> PathBuf {
+        let canonical_dir = self.pipeline.articles_dir().canonicalize().ok();
+        match canonical_dir.and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf)) {
+            Some(relative) => self.pipeline.articles_dir().join(relative),
+            None => path.to_path_buf(),
+        }
+    }
 
-        if self.verbose {
-            println!("   📝 Title: {}", metadata.title);
-            println!("   🆔 Slug: {}", slug);
-            println!("   🏠 Home display: {}", metadata.home_display);
-            if let Some(category) = &metadata.category {
-                println!("   📂 Category: {}", category);
-            }
-            println!("   ⭐ Importance: {}", metadata.importance);
-            if !outbound_links.is_empty() {
-                println!("   🔗 Outbound links: {}", outbound_links.len());
-                for link in &outbound_links {
-                    println!("      → {} ({})", link.target_slug, 
-                        match link.link_type {
-                            khimoo_portfolio::LinkType::WikiLink => "wiki",
-                            khimoo_portfolio::LinkType::MarkdownLink => "markdown",
+    /// Re-parse changed files and drop deleted ones.
+    /// Returns false when nothing in the article set actually changed.
+    fn apply_changes(&self, articles: &mut BTreeMap<PathBuf, ParsedArticle>, changed_paths: &BTreeSet<PathBuf>) -> bool {
+        let mut updated = false;
+        
+        for path in changed_paths {
+            if path.is_file() {
+                match self.pipeline.parse_source(path) {
+                    Ok(parsed) => {
+                        if self.verbose {
+                            println!("✅ Reprocessed: {} - '{}'", path.display(), parsed.article.title);
                         }
-                    );
+                        articles.insert(path.clone(), parsed);
+                        updated = true;
+                    }
+                    Err(e) => {
+                        // Keep the last good version so a half-written file
+                        // doesn't drop the article from the generated data
+                        eprintln!("❌ Error processing {}: {:#}", path.display(), e);
+                    }
+                }
+            } else if articles.remove(path).is_some() {
+                if self.verbose {
+                    println!("🗑️  Removed: {}", path.display());
                 }
+                updated = true;
             }
-            if !metadata.tags.is_empty() {
-                println!("   🏷️  Tags: {:?}", metadata.tags);
+        }
+        
+        updated
+    }
+
+    /// Drop unpublished articles, calculate inbound counts, fill dates, publish images,
+    /// render HTML, build the link graph and write all output files
+    fn generate_outputs(&self, mut articles: Vec<ProcessedArticle>) -> Result<(Vec<ProcessedArticle>, LinkGraphData)> {
+        // Leave out drafts and articles scheduled for later
+        if !self.include_drafts {
+            let skipped = ArticlePipeline::remove_unpublished(&mut articles, Utc::now());
+            if skipped > 0 {
+                println!("📝 Skipped {} draft or scheduled articles (use --include-drafts to build them)", skipped);
             }
         }
+        
+        // Calculate inbound link counts
+        ArticlePipeline::calculate_inbound_counts(&mut articles);
+        
+        // Fill dates missing from front matter with commit dates
+        if let Some(git_dates) = &self.git_dates {
+            let filled = git_dates.fill_missing_dates(&mut articles);
+            if self.verbose {
+                println!("📅 Filled dates of {} articles from git history", filled);
+            }
+        }
+        
+        // Copy local images into the output and point the references at them
+        let assets = self.assets.publish_articles(&mut articles)
+            .context("Failed to publish images")?;
+        if self.verbose {
+            println!("🖼️  Published {} images to {:?} ({} files written, {} stale removed)",
+                assets.images, self.assets.asset_dir(), assets.written, assets.removed);
+        }
+        if assets.missing > 0 {
+            println!("⚠️  Missing images: {} (run validate-links for details)", assets.missing);
+        }
+        if assets.outside_articles > 0 {
+            println!("⚠️  Skipped images outside the articles directory: {} (run validate-links for details)", assets.outside_articles);
+        }
+        
+        // Render HTML with links resolved against the full article set
+        self.pipeline.render_articles(&mut articles);
+        
+        // Build link graph
+        let link_graph = ArticlePipeline::build_link_graph(&articles);
+        
+        if self.verbose {
+            println!("🕸️  Built link graph with {} nodes and {} connections", 
+                link_graph.graph.len(), link_graph.total_connections);
+            println!("   🔗 Bidirectional pairs: {}", link_graph.bidirectional_pairs);
+        }
+        
+        // Write output files
+        self.write_articles_data(&articles)
+            .context("Failed to write articles data")?;
+        self.write_article_index(&articles)
+            .context("Failed to write article index")?;
+        self.write_article_contents(&articles)
+            .context("Failed to write article content files")?;
+        self.write_link_graph_data(&link_graph)
+            .context("Failed to write link graph data")?;
+        
+        Ok((articles, link_graph))
+    }
 
-        Ok(ProcessedArticle {
-            slug,
-            title: metadata.title.clone(),
-            content: markdown_content,
-            metadata,
-            file_path: file_path.to_string_lossy().to_string(),
-            outbound_links,
-            inbound_count: 0, // Will be calculated later
-            processed_at: Utc::now().to_rfc3339(),
-        })
+    /// Persist the build cache, if one is configured
+    fn save_cache<'a>(&self, parsed: impl IntoIterator<Item = &'a ParsedArticle>) -> Result<()> {
+        if let Some(cache_file) = &self.cache_file {
+            BuildCache::from_parsed(parsed).save(cache_file)?;
+        }
+        Ok(())
+    }
+
+    fn load_and_parse_articles(&self) -> Result<Vec<ParsedArticle>> {
+        let paths = self.pipeline.collect_article_paths();
+        let results = self.pipeline.parse_articles(&paths);
+        self.report_article_results(&paths, results)
     }
 
-    fn generate_slug(&self, file_path: &Path) -> String {
-        file_path
-            .file_stem()
-            .unwrap()
-            .to_string_lossy()
-            .to_string()
-            .to_lowercase()
-            .replace(' ', "-")
+    fn load_and_parse_articles_parallel(&self) -> Result<Vec<ParsedArticle>> {
+        let paths = self.pipeline.collect_article_paths();
+        let results = self.pipeline.parse_articles_parallel(&paths);
+        self.report_article_results(&paths, results)
     }
 
-    fn calculate_inbound_counts(&self, mut articles: Vec<ProcessedArticle>) -> Result<Vec<ProcessedArticle>> {
-        // Create a map of slug -> index for quick lookup
-        let slug_to_index: HashMap<String, usize> = articles
+    /// Report results in path order to keep verbose output readable
+    fn report_article_results(&self, paths: &[PathBuf], results: Vec<Result<ParsedArticle>>) -> Result<Vec<ParsedArticle>> {
+        paths
             .iter()
-            .enumerate()
-            .map(|(i, article)| (article.slug.clone(), i))
-            .collect();
+            .zip(results)
+            .map(|(path, article)| self.report_article_result(path, article))
+            .collect()
+    }
 
-        // Count inbound links by collecting them first
-        let mut inbound_counts: HashMap<String, usize> = HashMap::new();
-        
-        for article in &articles {
-            for link in &article.outbound_links {
-                if slug_to_index.contains_key(&link.target_slug) {
-                    *inbound_counts.entry(link.target_slug.clone()).or_insert(0) += 1;
+    fn report_article_result(&self, path: &Path, parsed: Result<ParsedArticle>) -> Result<ParsedArticle> {
+        match parsed {
+            Ok(parsed) => {
+                if self.verbose {
+                    println!("{} {}: {} - '{}'", 
+                        if parsed.from_cache { "♻️ " } else { "✅" },
+                        if parsed.from_cache { "Unchanged" } else { "Processed" },
+                        path.display(), 
+                        parsed.article.title
+                    );
+                    self.print_article_details(&parsed.article);
                 }
+                Ok(parsed)
+            }
+            Err(e) => {
+                eprintln!("❌ Error processing {}: {:#}", path.display(), e);
+                Err(e)
             }
         }
-
-        // Apply the counts
-        for article in &mut articles {
-            article.inbound_count = inbound_counts.get(&article.slug).copied().unwrap_or(0);
-        }
-
-        Ok(articles)
     }
 
-    fn build_link_graph(&self, articles: &[ProcessedArticle]) -> Result<LinkGraphData> {
-        let mut graph = HashMap::new();
-        let article_slugs: std::collections::HashSet<_> = 
-            articles.iter().map(|a| &a.slug).collect();
-        
-        // First pass: create nodes and direct connections
-        for article in articles {
-            let mut connections = Vec::new();
-            
-            // Process outbound links (direct links only)
+    fn print_article_details(&self, article: &ProcessedArticle) {
+        println!("   📝 Title: {}", article.metadata.title);
+        println!("   🆔 Slug: {}", article.slug);
+        println!("   🏠 Home display: {}", article.metadata.home_display);
+        if let Some(category) = &article.metadata.category {
+            println!("   📂 Category: {}", category);
+        }
+        println!("   ⭐ Importance: {}", article.metadata.importance);
+        if !article.outbound_links.is_empty() {
+            println!("   🔗 Outbound links: {}", article.outbound_links.len());
             for link in &article.outbound_links {
-                if article_slugs.contains(&link.target_slug) {
-                    connections.push(GraphConnection {
-                        target: link.target_slug.clone(),
-                        connection_type: ConnectionType::DirectLink,
-                        bidirectional: false,
-                    });
-                }
-            }
-            
-            graph.insert(article.slug.clone(), GraphNode {
-                connections,
-                inbound_count: article.inbound_count,
-            });
-        }
-        
-        // Second pass: detect bidirectional links
-        let mut bidirectional_pairs = std::collections::HashSet::new();
-        
-        for (source_slug, source_node) in &graph {
-            for connection in &source_node.connections {
-                let target_slug = &connection.target;
-                
-                // Check if target also links back to source
-                if let Some(target_node) = graph.get(target_slug) {
-                    let has_backlink = target_node.connections
-                        .iter()
-                        .any(|c| c.target == *source_slug);
-                    
-                    if has_backlink {
-                        // Create a canonical pair (smaller slug first) to avoid duplicates
-                        let pair = if source_slug < target_slug {
-                            (source_slug.clone(), target_slug.clone())
-                        } else {
-                            (target_slug.clone(), source_slug.clone())
-                        };
-                        bidirectional_pairs.insert(pair);
+                println!("      → {} ({})", link.target_slug, 
+                    match link.link_type {
+                        LinkType::WikiLink => "wiki",
+                        LinkType::MarkdownLink => "markdown",
                     }
-                }
+                );
             }
         }
-        
-        // Third pass: update bidirectional connections
-        for (slug1, slug2) in &bidirectional_pairs {
-            // Update connections in both nodes
-            if let Some(node1) = graph.get_mut(slug1) {
-                for connection in &mut node1.connections {
-                    if connection.target == *slug2 {
-                        connection.connection_type = ConnectionType::Bidirectional;
-                        connection.bidirectional = true;
-                    }
-                }
-            }
-            
-            if let Some(node2) = graph.get_mut(slug2) {
-                for connection in &mut node2.connections {
-                    if connection.target == *slug1 {
-                        connection.connection_type = ConnectionType::Bidirectional;
-                        connection.bidirectional = true;
-                    }
-                }
-            }
+        if !article.metadata.tags.is_empty() {
+            println!("   🏷️  Tags: {:?}", article.metadata.tags);
         }
+    }
+
+    fn write_articles_data(&self, articles: &[ProcessedArticle]) -> Result<()> {
+        let articles_data = ArticlePipeline::build_articles_data(articles);
         
-        let total_connections = graph.values()
-            .map(|node| node.connections.len())
-            .sum();
+        let output_path = self.output_dir.join("articles.json");
+        let written = write_json_output(&output_path, &articles_data)
+            .with_context(|| format!("Failed to write articles data to {:?}", output_path))?;
         
         if self.verbose {
-            println!("🕸️  Built link graph with {} nodes and {} connections", 
-                graph.len(), total_connections);
-            println!("   🔗 Bidirectional pairs: {}", bidirectional_pairs.len());
+            self.report_output("📄", "articles data", &output_path, written);
         }
         
-        Ok(LinkGraphData {
-            graph,
-            generated_at: Utc::now().to_rfc3339(),
-            total_connections,
-        })
+        Ok(())
     }
 
-    fn write_articles_data(&self, articles: &[ProcessedArticle]) -> Result<()> {
-        let articles_data = ArticlesData {
-            articles: articles.to_vec(),
-            generated_at: Utc::now().to_rfc3339(),
-            total_count: articles.len(),
-            home_articles: articles
-                .iter()
-                .filter(|a| a.metadata.home_display)
-                .map(|a| a.slug.clone())
-                .collect(),
-        };
+    fn write_article_index(&self, articles: &[ProcessedArticle]) -> Result<()> {
+        let article_index = ArticlePipeline::build_article_index(articles);
         
-        let output_path = self.output_dir.join("articles.json");
-        let json = serde_json::to_string_pretty(&articles_data)
-            .context("Failed to serialize articles data")?;
+        let output_path = self.output_dir.join("article-index.json");
+        let written = write_json_output(&output_path, &article_index)
+            .with_context(|| format!("Failed to write article index to {:?}", output_path))?;
         
-        std::fs::write(&output_path, json)
-            .with_context(|| format!("Failed to write articles data to {:?}", output_path))?;
+        if self.verbose {
+            self.report_output("🗂️ ", "article index", &output_path, written);
+        }
+        
+        Ok(())
+    }
+
+    /// Write one content file per article and remove files left behind by
+    /// articles that no longer exist
+    fn write_article_contents(&self, articles: &[ProcessedArticle]) -> Result<()> {
+        let content_dir = self.output_dir.join(ARTICLE_CONTENT_DIR);
+        std::fs::create_dir_all(&content_dir)
+            .with_context(|| format!("Failed to create {:?}", content_dir))?;
+        
+        let mut current = HashSet::new();
+        let mut updated = 0;
+        for article in articles {
+            let output_path = self.output_dir.join(article_content_path(&article.slug));
+            if write_json_output(&output_path, &ArticlePipeline::build_article_content(article))
+                .with_context(|| format!("Failed to write article content to {:?}", output_path))?
+            {
+                updated += 1;
+            }
+            current.insert(output_path);
+        }
+        
+        for entry in std::fs::read_dir(&content_dir)? {
+            let path = entry?.path();
+            let is_json = path.extension().is_some_and(|ext| ext == "json");
+            if is_json && !current.contains(&path) {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove stale content file {:?}", path))?;
+                if self.verbose {
+                    println!("🗑️  Removed stale content file: {:?}", path);
+                }
+            }
+        }
         
         if self.verbose {
-            println!("📄 Written articles data to: {:?}", output_path);
+            println!("📄 Updated {} of {} article content files in: {:?}", updated, current.len(), content_dir);
         }
         
         Ok(())
@@ -395,19 +543,24 @@ impl ArticleProcessor {
 
     fn write_link_graph_data(&self, link_graph: &LinkGraphData) -> Result<()> {
         let output_path = self.output_dir.join("link-graph.json");
-        let json = serde_json::to_string_pretty(link_graph)
-            .context("Failed to serialize link graph data")?;
-        
-        std::fs::write(&output_path, json)
+        let written = write_json_output(&output_path, link_graph)
             .with_context(|| format!("Failed to write link graph data to {:?}", output_path))?;
         
         if self.verbose {
-            println!("🕸️  Written link graph data to: {:?}", output_path);
+            self.report_output("🕸️ ", "link graph data", &output_path, written);
         }
         
         Ok(())
     }
 
+    fn report_output(&self, icon: &str, name: &str, path: &Path, written: bool) {
+        if written {
+            println!("{} Written {} to: {:?}", icon, name, path);
+        } else {
+            println!("{} Unchanged {}: {:?}", icon, name, path);
+        }
+    }
+
     fn display_summary(&self, articles: &[ProcessedArticle], link_graph: &LinkGraphData) {
         println!("\n📊 Processing Summary:");
         println!("   📚 Total articles: {}", articles.len());
@@ -423,11 +576,7 @@ impl ArticleProcessor {
         
         // Link graph statistics
         println!("   🕸️  Link graph connections: {}", link_graph.total_connections);
-        let bidirectional_count = link_graph.graph.values()
-            .flat_map(|node| &node.connections)
-            .filter(|conn| conn.bidirectional)
-            .count() / 2; // Divide by 2 since bidirectional links are counted twice
-        println!("   ↔️  Bidirectional connections: {}", bidirectional_count);
+        println!("   ↔️  Bidirectional connections: {}", link_graph.bidirectional_pairs);
         
         // Category breakdown
         let mut categories: HashMap<String, usize> = HashMap::new();
@@ -454,7 +603,7 @@ impl ArticleProcessor {
         if !all_tags.is_empty() {
             println!("   🏷️  Most common tags:");
             let mut tag_counts: Vec<_> = all_tags.into_iter().collect();
-            tag_counts.sort_by(|a, b| b.1.cmp(&a.1));
+            tag_counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
             for (tag, count) in tag_counts.into_iter().take(5) {
                 println!("      {}: {}", tag, count);
             }
@@ -478,4 +627,296 @@ impl ArticleProcessor {
             println!("   ✅ All links valid");
         }
     }
-}
\ No newline at end of file
+}
+
+/// Articles currently known in watch mode, in path order
+fn current_articles(articles: &BTreeMap<PathBuf, ParsedArticle>) -> Vec<ProcessedArticle> {
+    articles.values().map(|parsed| parsed.article.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khimoo_portfolio::article_processing::{ArticleContentData, ArticleIndex};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn load_article_map(processor: &ArticleProcessor) -> Result<BTreeMap<PathBuf, ParsedArticle>> {
+        Ok(processor
+            .load_and_parse_articles()?
+            .into_iter()
+            .map(|parsed| (PathBuf::from(&parsed.article.file_path), parsed))
+            .collect())
+    }
+
+    #[test]
+    fn test_apply_changes_reparses_and_removes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(&articles_dir)?;
+
+        let article1 = articles_dir.join("article1.md");
+        let article2 = articles_dir.join("article2.md");
+        fs::write(&article1, "---\ntitle: \"Article 1\"\n---\n\nNo links yet.\n")?;
+        fs::write(&article2, "---\ntitle: \"Article 2\"\n---\n\n# Article 2\n")?;
+
+        let processor = ArticleProcessor::new(articles_dir, output_dir, false)?;
+        let mut articles = load_article_map(&processor)?;
+        assert_eq!(articles.len(), 2);
+        assert!(articles[&article1].article.outbound_links.is_empty());
+
+        // Edit one article and delete the other
+        fs::write(&article1, "---\ntitle: \"Article 1\"\n---\n\nNow links to [[article2]].\n")?;
+        fs::remove_file(&article2)?;
+
+        let changed: BTreeSet<PathBuf> = [article1.clone(), article2.clone()].into_iter().collect();
+        assert!(processor.apply_changes(&mut articles, &changed));
+
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[&article1].article.outbound_links.len(), 1);
+        assert_eq!(articles[&article1].article.outbound_links[0].target_slug, "article2");
+
+        // Nothing left to change
+        let unchanged: BTreeSet<PathBuf> = [article2].into_iter().collect();
+        assert!(!processor.apply_changes(&mut articles, &unchanged));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_outputs_writes_files_atomically() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(&articles_dir)?;
+        fs::create_dir_all(&output_dir)?;
+
+        fs::write(articles_dir.join("a.md"), "---\ntitle: \"A\"\n---\n\nSee [[b]].\n")?;
+        fs::write(articles_dir.join("b.md"), "---\ntitle: \"B\"\n---\n\nSee [[a]].\n")?;
+
+        let processor = ArticleProcessor::new(articles_dir, output_dir.clone(), false)?;
+        let articles = load_article_map(&processor)?;
+        let (articles_with_counts, link_graph) = processor.generate_outputs(current_articles(&articles))?;
+
+        assert!(articles_with_counts.iter().all(|a| a.inbound_count == 1));
+        assert!(articles_with_counts[0].html.contains(r#"<a href="/article/b" class="article-link" data-article-slug="b""#));
+        assert_eq!(link_graph.total_connections, 2);
+        assert_eq!(link_graph.bidirectional_pairs, 1);
+        assert!(output_dir.join("articles.json").exists());
+        assert!(output_dir.join("article-index.json").exists());
+        assert!(output_dir.join("articles/a.json").exists());
+        assert!(output_dir.join("articles/b.json").exists());
+        assert!(output_dir.join("link-graph.json").exists());
+        assert!(!output_dir.join("articles.json.tmp").exists());
+        assert!(!output_dir.join("link-graph.json.tmp").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_outputs_shards_article_content() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(&articles_dir)?;
+        fs::create_dir_all(&output_dir)?;
+
+        fs::write(articles_dir.join("a.md"), "---\ntitle: \"A\"\n---\n\nFirst paragraph of A.\n\nSee [[b]] and [[b]].\n")?;
+        fs::write(articles_dir.join("b.md"), "---\ntitle: \"B\"\n---\n\nBody of B.\n")?;
+
+        let processor = ArticleProcessor::new(articles_dir.clone(), output_dir.clone(), false)?;
+        let mut articles = load_article_map(&processor)?;
+        processor.generate_outputs(current_articles(&articles))?;
+
+        let index: ArticleIndex = serde_json::from_str(&fs::read_to_string(output_dir.join("article-index.json"))?)?;
+        assert_eq!(index.total_count, 2);
+        let a = &index.articles[0];
+        assert_eq!(a.summary.as_deref(), Some("First paragraph of A."));
+        assert_eq!(a.outbound_targets, vec!["b".to_string()]);
+        assert_eq!(a.outbound_count, 2);
+        assert_eq!(index.articles[1].inbound_count, 2);
+
+        let content: ArticleContentData = serde_json::from_str(&fs::read_to_string(output_dir.join("articles/b.json"))?)?;
+        assert_eq!(content.article.slug, "b");
+        assert!(content.article.content.contains("Body of B."));
+
+        // Deleting an article removes its content file on the next run
+        fs::remove_file(articles_dir.join("b.md"))?;
+        let removed: BTreeSet<PathBuf> = [articles_dir.join("b.md")].into_iter().collect();
+        assert!(processor.apply_changes(&mut articles, &removed));
+        processor.generate_outputs(current_articles(&articles))?;
+        assert!(output_dir.join("articles/a.json").exists());
+        assert!(!output_dir.join("articles/b.json").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_outputs_publishes_images() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(articles_dir.join("posts"))?;
+        fs::create_dir_all(articles_dir.join("img"))?;
+        image::RgbImage::new(1200, 600).save(articles_dir.join("img/Photo.png"))?;
+
+        let article = articles_dir.join("posts/a.md");
+        fs::write(&article, "---\ntitle: \"A\"\nauthor_image: ../img/Photo.png\n---\n\n![A photo](../img/Photo.png \"Caption\")\n")?;
+
+        let processor = ArticleProcessor::new(articles_dir.clone(), output_dir.clone(), false)?;
+        let mut articles = load_article_map(&processor)?;
+        let (articles_with_counts, _) = processor.generate_outputs(current_articles(&articles))?;
+
+        let a = &articles_with_counts[0];
+        let published = a.assets[0].published.as_ref().expect("image should be published");
+        assert!(published.url.starts_with("/data/assets/photo."));
+        assert!(published.url.ends_with(".png"));
+        assert_eq!((published.width, published.height), (Some(1200), Some(600)));
+        assert_eq!(published.variants.iter().map(|v| v.width).collect::<Vec<_>>(), vec![480, 960]);
+        assert_eq!(a.metadata.author_image.as_deref(), Some(published.url.as_str()));
+        assert!(a.html.contains(&format!(r#"src="{}""#, published.url)));
+        assert!(a.html.contains(&format!(r#"srcset="{} 480w, "#, published.variants[0].url)));
+        assert!(a.html.contains(r#"alt="A photo""#));
+        assert!(a.html.contains(r#"title="Caption""#));
+
+        let asset_dir = output_dir.join("assets");
+        let variant = asset_dir.join(published.variants[0].url.trim_start_matches("/data/assets/"));
+        assert_eq!(image::image_dimensions(&variant)?, (480, 240));
+        assert_eq!(fs::read_dir(&asset_dir)?.count(), 3);
+
+        // Images that are no longer referenced are removed on the next run
+        fs::write(&article, "---\ntitle: \"A\"\n---\n\nNo pictures.\n")?;
+        let changed: BTreeSet<PathBuf> = [article].into_iter().collect();
+        assert!(processor.apply_changes(&mut articles, &changed));
+        processor.generate_outputs(current_articles(&articles))?;
+        assert_eq!(fs::read_dir(&asset_dir)?.count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_outputs_skips_drafts() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(&articles_dir)?;
+
+        fs::write(articles_dir.join("a.md"), "---\ntitle: \"A\"\n---\n\nSee [[draft]] and [[later]].\n")?;
+        fs::write(articles_dir.join("draft.md"), "---\ntitle: \"Draft\"\ndraft: true\n---\n\nSee [[a]].\n")?;
+        fs::write(articles_dir.join("later.md"), "---\ntitle: \"Later\"\npublish_at: 2999-01-01\n---\n")?;
+        fs::write(articles_dir.join("earlier.md"), "---\ntitle: \"Earlier\"\npublish_at: 2000-01-01\n---\n")?;
+
+        let processor = ArticleProcessor::new(articles_dir.clone(), output_dir.clone(), false)?;
+        let articles = load_article_map(&processor)?;
+        let (published, link_graph) = processor.generate_outputs(current_articles(&articles))?;
+
+        let slugs: Vec<_> = published.iter().map(|a| a.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["a", "earlier"]);
+        // Links into left-out articles are rendered as missing
+        assert_eq!(published[0].inbound_count, 0);
+        assert!(published[0].html.contains("broken-link"));
+        assert_eq!(link_graph.total_connections, 0);
+        assert!(!output_dir.join("articles/draft.json").exists());
+
+        let processor = processor.with_include_drafts(true);
+        let (published, _) = processor.generate_outputs(current_articles(&articles))?;
+        assert_eq!(published.len(), 4);
+        assert!(output_dir.join("articles/draft.json").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        fs::create_dir_all(articles_dir.join("nested"))?;
+
+        for i in 0..20 {
+            let next = (i + 1) % 20;
+            let dir = if i % 3 == 0 { articles_dir.join("nested") } else { articles_dir.clone() };
+            fs::write(
+                dir.join(format!("article-{:02}.md", i)),
+                format!("---\ntitle: \"Article {i}\"\n---\n\nSee [[article-{next:02}]] and [back](article-00).\n"),
+            )?;
+        }
+
+        let output_dir = temp_dir.path().join("data");
+        fs::create_dir_all(&output_dir)?;
+
+        let processor = ArticleProcessor::new(articles_dir, output_dir, false)?;
+        let without_hashes = |parsed: Vec<ParsedArticle>| -> Vec<ProcessedArticle> {
+            parsed.into_iter().map(|p| p.article).collect()
+        };
+        let sequential = without_hashes(processor.load_and_parse_articles()?);
+        let parallel = without_hashes(processor.load_and_parse_articles_parallel()?);
+        assert_eq!(sequential.len(), 20);
+
+        // Everything except the per-article timestamp must match exactly
+        let without_timestamps = |articles: &[ProcessedArticle]| -> Result<serde_json::Value> {
+            let articles: Vec<_> = articles
+                .iter()
+                .cloned()
+                .map(|a| ProcessedArticle { processed_at: String::new(), ..a })
+                .collect();
+            Ok(serde_json::to_value(articles)?)
+        };
+        assert_eq!(without_timestamps(&sequential)?, without_timestamps(&parallel)?);
+
+        let (_, sequential_graph) = processor.generate_outputs(sequential)?;
+        let (_, parallel_graph) = processor.generate_outputs(parallel)?;
+        assert_eq!(
+            serde_json::to_string(&sequential_graph.graph)?,
+            serde_json::to_string(&parallel_graph.graph)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_cache_keeps_unchanged_output_stable() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        let cache_file = temp_dir.path().join(".article-cache.json");
+        fs::create_dir_all(&articles_dir)?;
+
+        fs::write(articles_dir.join("a.md"), "---\ntitle: \"A\"\n---\n\nSee [[b]].\n")?;
+        fs::write(articles_dir.join("b.md"), "---\ntitle: \"B\"\n---\n\nNo links.\n")?;
+
+        let build = || -> Result<(Vec<ParsedArticle>, String, String)> {
+            let processor = ArticleProcessor::new(articles_dir.clone(), output_dir.clone(), false)?
+                .with_cache_file(cache_file.clone())?;
+            let parsed = processor.load_and_parse_articles()?;
+            processor.finish_processing(parsed.clone())?;
+            Ok((
+                parsed,
+                fs::read_to_string(output_dir.join("articles.json"))?,
+                fs::read_to_string(output_dir.join("link-graph.json"))?,
+            ))
+        };
+        fs::create_dir_all(&output_dir)?;
+
+        let (first, first_articles, first_graph) = build()?;
+        assert!(first.iter().all(|p| !p.from_cache));
+        assert!(cache_file.exists());
+
+        // Nothing changed: everything comes from the cache and the output is byte-identical
+        let (second, second_articles, second_graph) = build()?;
+        assert!(second.iter().all(|p| p.from_cache));
+        assert_eq!(first_articles, second_articles);
+        assert_eq!(first_graph, second_graph);
+
+        // Only the edited article is re-parsed and gets a new timestamp
+        fs::write(articles_dir.join("b.md"), "---\ntitle: \"B\"\n---\n\nNow links to [[a]].\n")?;
+        let (third, third_articles, _) = build()?;
+        assert!(third[0].from_cache);
+        assert!(!third[1].from_cache);
+        assert_eq!(third[0].article.processed_at, first[0].article.processed_at);
+        assert_eq!(third[1].article.outbound_links.len(), 1);
+        assert_ne!(first_articles, third_articles);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,31 @@
 use khimoo_portfolio::article_processing::{
-    FrontMatterParser, LinkExtractor, LinkValidator, ProcessedArticleRef,
-    ValidationReportFormatter, ValidationReport
+    generate_slug, write_atomically, ArticlePipeline, FrontMatterParser, InvalidFrontMatter, LinkFix, LinkValidator, ProcessedArticle, ProcessedArticleRef, ProjectConfig, Severity,
+    ValidationBaseline, ValidationOptions, ValidationReportFormatter, ValidationReport, ValidationSummary
 };
+use khimoo_portfolio::assets::find_asset_issues;
+use khimoo_portfolio::external_links::{ExternalLinkChecker, UreqClient, EXTERNAL_LINK_CACHE_FILE};
+use khimoo_portfolio::git_dates::repository_root;
 use anyhow::{Context, Result};
-use clap::Parser;
-use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use clap::{Parser, ValueEnum};
+use similar::TextDiff;
+use std::path::PathBuf;
+
+/// Validation passed (or the policy tolerated every issue)
+const EXIT_OK: i32 = 0;
+/// Error-severity issues were found
+const EXIT_ERRORS: i32 = 1;
+/// Warnings failed the run via `--fail-on warning` or `--max-warnings`
+const EXIT_WARNINGS: i32 = 2;
+/// The tool itself failed (unreadable articles, bad config, I/O errors)
+const EXIT_TOOL_FAILURE: i32 = 3;
+
+/// Lowest severity that makes the run fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum FailOn {
+    Error,
+    Warning,
+    Never,
+}
 
 #[derive(Parser)]
 #[command(name = "validate-links")]
@@ -19,7 +39,7 @@ struct Args {
     #[arg(short, long, default_value = "data")]
     output_dir: PathBuf,
     
-    /// Output format: console, json, ci, or all
+    /// Output format: console, json, ci, sarif, junit, or all
     #[arg(short, long, default_value = "console")]
     format: String,
     
@@ -27,8 +47,18 @@ struct Args {
     #[arg(short, long)]
     verbose: bool,
     
-    /// Exit with error code if validation fails
-    #[arg(long)]
+    /// Lowest issue severity that makes validation exit non-zero
+    /// (1 for errors, 2 for warnings; 3 means the tool itself failed)
+    #[arg(long, value_enum, default_value = "error")]
+    fail_on: FailOn,
+    
+    /// Fail with exit code 2 when more than N warnings are reported,
+    /// regardless of --fail-on
+    #[arg(long, value_name = "N")]
+    max_warnings: Option<usize>,
+    
+    /// Deprecated alias for `--fail-on error`
+    #[arg(long, hide = true)]
     fail_on_error: bool,
     
     /// Only show errors, suppress warnings
@@ -38,61 +68,228 @@ struct Args {
     /// Write reports to files even in console mode
     #[arg(long)]
     write_files: bool,
+    
+    /// Project config file with validation thresholds and rule severities
+    /// [default: khimoo.toml, if it exists]
+    #[arg(long)]
+    config: Option<PathBuf>,
+    
+    /// Baseline of known errors; only errors not listed in it are reported
+    /// and can fail the run
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+    
+    /// Write every current error to the --baseline file instead of
+    /// filtering against it
+    #[arg(long, requires = "baseline", conflicts_with = "prune_baseline")]
+    update_baseline: bool,
+    
+    /// Remove entries that are no longer reported from the --baseline file
+    #[arg(long, requires = "baseline")]
+    prune_baseline: bool,
+    
+    /// Rewrite broken links and invalid related_articles entries to their
+    /// suggestion when it is at least `fix_threshold` similar
+    #[arg(long)]
+    fix: bool,
+    
+    /// With --fix, print a unified diff instead of writing files. Only the
+    /// diff is written to stdout, so no report is printed.
+    #[arg(long, requires = "fix")]
+    dry_run: bool,
+    
+    /// Also check that external http(s) links respond, see [validation.external]
+    /// in the config for concurrency, retries and cache lifetime
+    #[arg(long)]
+    check_external: bool,
+    
+    /// Where external link results are cached between runs
+    #[arg(long, default_value = EXTERNAL_LINK_CACHE_FILE)]
+    external_cache: PathBuf,
+    
+    /// Report unknown front matter keys, malformed YAML or TOML and invalid
+    /// values as invalid-metadata errors instead of parsing leniently
+    #[arg(long)]
+    strict: bool,
+    
+    /// Shortest cycle of articles reported as a circular reference
+    /// (2 also reports bidirectional pairs); overrides the config file
+    #[arg(long)]
+    min_cycle_length: Option<usize>,
+    
+    /// Directory SARIF file paths are relative to, as code scanning expects
+    /// [default: root of the git repository containing the articles]
+    #[arg(long, value_name = "PATH")]
+    sarif_root: Option<PathBuf>,
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
-    
+fn main() {
+    let code = match run(Args::parse()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("❌ {:#}", e);
+            EXIT_TOOL_FAILURE
+        }
+    };
+    std::process::exit(code);
+}
+
+fn run(args: Args) -> Result<i32> {
     if args.verbose {
         println!("🔍 Validating links in {:?}", args.articles_dir);
         println!("📁 Output directory: {:?}", args.output_dir);
         println!("📋 Format: {}", args.format);
     }
     
-    let validator = LinkValidationTool::new(
+    let mut options = ProjectConfig::load_or_default(args.config.as_deref())?.validation;
+    if let Some(min_cycle_length) = args.min_cycle_length {
+        options.min_cycle_length = min_cycle_length;
+    }
+    
+    let external_checker = args.check_external.then(|| {
+        let client = UreqClient::new(std::time::Duration::from_secs(options.external.timeout_secs));
+        ExternalLinkChecker::new(Box::new(client), options.external.clone())
+            .with_cache_file(args.external_cache.clone())
+    });
+    
+    let mut validator = LinkValidationTool::new(
         args.articles_dir,
         args.output_dir,
         args.verbose,
         args.errors_only,
-    );
+    )?
+    .with_options(options)
+    .with_strict_front_matter(args.strict)
+    .with_sarif_root(args.sarif_root);
+    if let Some(checker) = external_checker {
+        validator = validator.with_external_checker(checker);
+    }
+    
+    if args.fix {
+        let fixed_files = validator.fix_articles(args.dry_run)
+            .context("Failed to fix articles")?;
+        if args.dry_run {
+            // stdout holds the patch, e.g. for `> fixes.patch`
+            eprintln!("🔧 Dry run: {} file(s) would change", fixed_files);
+            return Ok(EXIT_OK);
+        }
+        if fixed_files > 0 {
+            eprintln!("🔧 Fixed {} file(s); validating the result", fixed_files);
+        }
+    }
     
-    let report = validator.validate_all_articles()
+    let mut report = validator.validate_all_articles()
         .context("Failed to validate articles")?;
     
+    if let Some(baseline_path) = &args.baseline {
+        let baseline = if args.update_baseline {
+            let baseline = ValidationBaseline::from_report(&report);
+            baseline.save(baseline_path)?;
+            eprintln!("📌 Baseline written to {:?} ({} entries)", baseline_path, baseline.entries.len());
+            baseline
+        } else {
+            let mut baseline = ValidationBaseline::load(baseline_path)?;
+            if args.prune_baseline {
+                let resolved = baseline.prune(&report);
+                baseline.save(baseline_path)?;
+                eprintln!("🧹 Pruned {} resolved entries from {:?}", resolved.len(), baseline_path);
+                if args.verbose {
+                    for entry in &resolved {
+                        eprintln!("   • {} → {} ({})", entry.source_article, entry.target_reference, entry.error_type.rule_id());
+                    }
+                }
+            }
+            baseline
+        };
+        
+        let suppressed = baseline.filter_report(&mut report);
+        if suppressed > 0 {
+            eprintln!("📌 {} known error(s) suppressed by baseline", suppressed);
+        }
+    }
+    
     // Output the report in the requested format
     validator.output_report(&report, &args.format, args.write_files)
         .context("Failed to output validation report")?;
     
-    // Exit with error code if validation failed and fail_on_error is set
-    if args.fail_on_error && (report.summary.broken_links > 0 || report.summary.invalid_references > 0) {
-        std::process::exit(1);
+    let fail_on = if args.fail_on_error { FailOn::Error } else { args.fail_on };
+    let code = exit_code(&report.summary, fail_on, args.max_warnings);
+    match code {
+        EXIT_ERRORS => eprintln!("❌ Validation failed: {} error(s)", report.summary.total_errors),
+        EXIT_WARNINGS => eprintln!("⚠️  Validation failed: {} warning(s)", report.summary.total_warnings),
+        _ => {}
     }
     
-    Ok(())
+    Ok(code)
+}
+
+/// Decides the process exit code from the report summary and failure policy
+fn exit_code(summary: &ValidationSummary, fail_on: FailOn, max_warnings: Option<usize>) -> i32 {
+    let fails_on_errors = fail_on != FailOn::Never && summary.total_errors > 0;
+    let fails_on_warnings = (fail_on == FailOn::Warning && summary.total_warnings > 0)
+        || max_warnings.is_some_and(|max| summary.total_warnings > max);
+    
+    if fails_on_errors {
+        EXIT_ERRORS
+    } else if fails_on_warnings {
+        EXIT_WARNINGS
+    } else {
+        EXIT_OK
+    }
 }
 
 pub struct LinkValidationTool {
-    articles_dir: PathBuf,
+    pipeline: ArticlePipeline,
     output_dir: PathBuf,
     verbose: bool,
     errors_only: bool,
-    link_extractor: LinkExtractor,
+    options: ValidationOptions,
+    external_checker: Option<ExternalLinkChecker>,
+    strict_front_matter: bool,
+    sarif_root: Option<PathBuf>,
 }
 
 impl LinkValidationTool {
-    pub fn new(articles_dir: PathBuf, output_dir: PathBuf, verbose: bool, errors_only: bool) -> Self {
-        Self {
-            articles_dir,
+    pub fn new(articles_dir: PathBuf, output_dir: PathBuf, verbose: bool, errors_only: bool) -> Result<Self> {
+        Ok(Self {
+            pipeline: ArticlePipeline::new(articles_dir)?,
             output_dir,
             verbose,
             errors_only,
-            link_extractor: LinkExtractor::new().expect("Failed to create LinkExtractor"),
-        }
+            options: ValidationOptions::default(),
+            external_checker: None,
+            strict_front_matter: false,
+            sarif_root: None,
+        })
+    }
+
+    pub fn with_options(mut self, options: ValidationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Check external links with `checker` as part of validation
+    pub fn with_external_checker(mut self, checker: ExternalLinkChecker) -> Self {
+        self.external_checker = Some(checker);
+        self
+    }
+
+    /// Check front matter strictly, see [`FrontMatterParser::check_strict`]
+    pub fn with_strict_front_matter(mut self, strict: bool) -> Self {
+        self.strict_front_matter = strict;
+        self
+    }
+    
+    /// Directory SARIF paths are made relative to, instead of the root of
+    /// the git repository containing the articles
+    pub fn with_sarif_root(mut self, sarif_root: Option<PathBuf>) -> Self {
+        self.sarif_root = sarif_root;
+        self
     }
 
     pub fn validate_all_articles(&self) -> Result<ValidationReport> {
         if self.verbose {
-            println!("📚 Loading articles from {:?}", self.articles_dir);
+            println!("📚 Loading articles from {:?}", self.pipeline.articles_dir());
         }
         
         // Load all articles
@@ -104,8 +301,25 @@ impl LinkValidationTool {
             println!("🔍 Running validation...");
         }
         
-        // Create validator and run validation
-        let validator = LinkValidator::new(&articles);
+        let article_refs: Vec<ProcessedArticleRef> = articles.iter().map(ProcessedArticleRef::from).collect();
+        let mut validator = LinkValidator::with_options(&article_refs, self.options.clone())
+            .with_asset_issues(find_asset_issues(self.pipeline.articles_dir(), &articles));
+        
+        if let Some(checker) = &self.external_checker {
+            let external_links: usize = articles.iter().map(|a| a.external_links.len()).sum();
+            if self.verbose {
+                println!("🌐 Checking {} external links...", external_links);
+            }
+            let broken = checker.check_articles(&articles)
+                .context("Failed to check external links")?;
+            validator = validator.with_broken_external_links(broken);
+        }
+        
+        if self.strict_front_matter {
+            validator = validator.with_invalid_front_matter(self.check_front_matter()?);
+        }
+        
+        // Run validation
         let report = validator.validate_all()
             .context("Failed to run validation")?;
         
@@ -117,26 +331,74 @@ impl LinkValidationTool {
         Ok(report)
     }
 
-    fn load_articles(&self) -> Result<Vec<ProcessedArticleRef>> {
+    /// Apply confident suggestions to broken references in every article.
+    /// With `dry_run`, prints a unified diff per file instead of writing.
+    /// Returns the number of files that changed (or would change).
+    pub fn fix_articles(&self, dry_run: bool) -> Result<usize> {
+        let articles = self.load_articles()
+            .context("Failed to load articles")?;
+        let article_refs: Vec<ProcessedArticleRef> = articles.iter().map(ProcessedArticleRef::from).collect();
+        let validator = LinkValidator::with_options(&article_refs, self.options.clone());
+        
+        let mut changed_files = 0;
+        for article in &articles {
+            let content = std::fs::read_to_string(&article.file_path)
+                .with_context(|| format!("Failed to read {}", article.file_path))?;
+            let (fixed, fixes) = validator.fix_article_source(&article.slug, &content);
+            if fixes.is_empty() || fixed == content {
+                continue;
+            }
+            changed_files += 1;
+            
+            for fix in &fixes {
+                eprintln!("{}", Self::format_fix(&article.file_path, fix));
+            }
+            
+            if dry_run {
+                let diff = TextDiff::from_lines(&content, &fixed);
+                print!("{}", diff.unified_diff()
+                    .header(&format!("a/{}", article.file_path), &format!("b/{}", article.file_path)));
+            } else {
+                write_atomically(std::path::Path::new(&article.file_path), &fixed)
+                    .with_context(|| format!("Failed to write {}", article.file_path))?;
+            }
+        }
+        
+        if changed_files == 0 && self.verbose {
+            eprintln!("🔧 No confident fixes found");
+        }
+        
+        Ok(changed_files)
+    }
+
+    fn format_fix(file_path: &str, fix: &LinkFix) -> String {
+        format!(
+            "🔧 {}:{} '{}' → '{}' (similarity {:.2})",
+            file_path, fix.line, fix.original, fix.replacement, fix.confidence
+        )
+    }
+
+    fn load_articles(&self) -> Result<Vec<ProcessedArticle>> {
         let mut articles = Vec::new();
         
-        for entry in WalkDir::new(&self.articles_dir)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().map_or(false, |ext| ext == "md"))
-        {
-            match self.load_article_file(entry.path()) {
+        for path in self.pipeline.collect_article_paths() {
+            match self.pipeline.parse_article(&path) {
                 Ok(article) => {
                     if self.verbose {
                         println!("   📄 Loaded: {} - '{}'", 
-                            entry.path().display(), 
+                            path.display(), 
                             article.title
                         );
                     }
                     articles.push(article);
                 }
+                // The strict check reports why, so the remaining
+                // articles are still validated
+                Err(e) if self.strict_front_matter => {
+                    eprintln!("⚠️  Skipping {}: {:#}", path.display(), e);
+                }
                 Err(e) => {
-                    eprintln!("❌ Error loading {}: {}", entry.path().display(), e);
+                    eprintln!("❌ Error loading {}: {}", path.display(), e);
                     return Err(e);
                 }
             }
@@ -145,42 +407,20 @@ impl LinkValidationTool {
         Ok(articles)
     }
 
-    fn load_article_file(&self, file_path: &Path) -> Result<ProcessedArticleRef> {
-        // Read file content
-        let content = std::fs::read_to_string(file_path)
-            .with_context(|| format!("Failed to read file: {:?}", file_path))?;
-
-        // Parse front matter
-        let (metadata, markdown_content) = FrontMatterParser::parse(&content)
-            .with_context(|| format!("Failed to parse front matter in: {:?}", file_path))?;
-
-        // Validate metadata
-        FrontMatterParser::validate_metadata(&metadata)
-            .with_context(|| format!("Invalid metadata in: {:?}", file_path))?;
-
-        // Extract links from content
-        let outbound_links = self.link_extractor.extract_links(&markdown_content);
-
-        // Generate slug from file path
-        let slug = self.generate_slug(file_path);
-
-        Ok(ProcessedArticleRef {
-            slug,
-            title: metadata.title.clone(),
-            metadata,
-            outbound_links,
-            file_path: file_path.to_string_lossy().to_string(),
-        })
-    }
-
-    fn generate_slug(&self, file_path: &Path) -> String {
-        file_path
-            .file_stem()
-            .unwrap()
-            .to_string_lossy()
-            .to_string()
-            .to_lowercase()
-            .replace(' ', "-")
+    fn check_front_matter(&self) -> Result<Vec<InvalidFrontMatter>> {
+        let mut invalid = Vec::new();
+        for path in self.pipeline.collect_article_paths() {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {:?}", path))?;
+            if let Err(errors) = FrontMatterParser::check_strict(&content) {
+                invalid.push(InvalidFrontMatter {
+                    source_article: generate_slug(&path),
+                    file_path: path.to_string_lossy().to_string(),
+                    errors,
+                });
+            }
+        }
+        Ok(invalid)
     }
 
     fn print_validation_summary(&self, report: &ValidationReport) {
@@ -200,6 +440,14 @@ impl LinkValidationTool {
             println!("   🏝️  Orphaned articles: {}", report.summary.orphaned_articles);
         }
         
+        if report.summary.circular_references > 0 {
+            println!("   🔄 Circular references: {}", report.summary.circular_references);
+        }
+        
+        if report.summary.broken_external_links > 0 {
+            println!("   🌐 Broken external links: {}", report.summary.broken_external_links);
+        }
+        
         println!("   📄 Articles with errors: {}", report.summary.articles_with_errors);
         
         if !self.errors_only {
@@ -229,6 +477,14 @@ impl LinkValidationTool {
                 let ci_output = ValidationReportFormatter::format_ci_summary(report);
                 println!("{}", ci_output);
             }
+            "sarif" => {
+                let sarif_output = self.format_sarif(report)?;
+                println!("{}", sarif_output);
+            }
+            "junit" => {
+                let junit_output = ValidationReportFormatter::format_junit(report);
+                println!("{}", junit_output);
+            }
             "all" => {
                 // Write all formats to files
                 self.write_report_files(report)?;
@@ -242,7 +498,7 @@ impl LinkValidationTool {
                 println!("{}", console_output);
             }
             _ => {
-                return Err(anyhow::anyhow!("Invalid format: {}. Use console, json, ci, or all", format));
+                return Err(anyhow::anyhow!("Invalid format: {}. Use console, json, ci, sarif, junit, or all", format));
             }
         }
         
@@ -261,25 +517,44 @@ impl LinkValidationTool {
         output.push_str(&format!("   📚 Total articles: {}\n", report.summary.total_articles));
         output.push_str(&format!("   🔗 Total links: {}\n", report.summary.total_links));
         
+        if report.summary.total_errors == 0 {
+            output.push_str("   ✅ No errors\n");
+        } else {
+            output.push_str(&format!("   ❌ Errors: {}\n", report.summary.total_errors));
+        }
+        
         if report.summary.broken_links > 0 {
             output.push_str(&format!("   ❌ Broken links: {}\n", report.summary.broken_links));
-        } else {
-            output.push_str("   ✅ All links valid\n");
         }
         
         if report.summary.invalid_references > 0 {
             output.push_str(&format!("   ⚠️  Invalid references: {}\n", report.summary.invalid_references));
         }
         
+        if report.summary.orphaned_articles > 0 {
+            output.push_str(&format!("   🏝️  Orphaned articles: {}\n", report.summary.orphaned_articles));
+        }
+        
+        if report.summary.circular_references > 0 {
+            output.push_str(&format!("   🔄 Circular references: {}\n", report.summary.circular_references));
+        }
+        
+        if report.summary.broken_external_links > 0 {
+            output.push_str(&format!("   🌐 Broken external links: {}\n", report.summary.broken_external_links));
+        }
+        
+        if report.summary.missing_assets > 0 {
+            output.push_str(&format!("   🖼️  Missing images: {}\n", report.summary.missing_assets));
+        }
+        
         output.push_str(&format!("   📄 Articles with errors: {}\n", report.summary.articles_with_errors));
         
         // Errors section only
-        if !report.errors.is_empty() {
+        let errors = ValidationReportFormatter::format_issues(report, Severity::Error);
+        if !errors.is_empty() {
             output.push_str("\n❌ Errors:\n");
-            for (i, error) in report.errors.iter().enumerate() {
-                output.push_str(&format!("{}. ", i + 1));
-                output.push_str(&ValidationReportFormatter::format_error(error));
-                output.push('\n');
+            for (i, error) in errors.iter().enumerate() {
+                output.push_str(&format!("{}. {}\n", i + 1, error));
             }
         }
         
@@ -294,6 +569,15 @@ impl LinkValidationTool {
         output
     }
 
+    /// SARIF report with paths relative to the `--sarif-root`, the git
+    /// repository root or the current directory, in that order
+    pub fn format_sarif(&self, report: &ValidationReport) -> Result<String> {
+        let root = self.sarif_root.clone()
+            .or_else(|| repository_root(self.pipeline.articles_dir()))
+            .unwrap_or_else(|| PathBuf::from("."));
+        ValidationReportFormatter::format_sarif(report, &root)
+    }
+    
     fn write_report_files(&self, report: &ValidationReport) -> Result<()> {
         // Create output directory
         std::fs::create_dir_all(&self.output_dir)
@@ -316,6 +600,7 @@ impl LinkValidationTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use khimoo_portfolio::article_processing::{ValidationErrorType, ValidationWarningType};
     use std::fs;
     use tempfile::TempDir;
 
@@ -356,7 +641,7 @@ This is a valid article.
         )?;
         
         // Create validation tool
-        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false);
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
         
         // Run validation
         let report = tool.validate_all_articles()?;
@@ -406,7 +691,7 @@ This links back to [Article 1](article1).
         )?;
         
         // Create validation tool
-        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false);
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
         
         // Run validation
         let report = tool.validate_all_articles()?;
@@ -420,6 +705,314 @@ This links back to [Article 1](article1).
         Ok(())
     }
 
+    #[test]
+    fn test_min_cycle_length() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("a.md"), "---\ntitle: A\n---\n[[b]]\n")?;
+        fs::write(articles_dir.join("b.md"), "---\ntitle: B\n---\n[[a]]\n")?;
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir.clone(), false, false)?;
+        assert_eq!(tool.validate_all_articles()?.summary.circular_references, 0);
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?
+            .with_options(ValidationOptions { min_cycle_length: 2, ..Default::default() });
+        let report = tool.validate_all_articles()?;
+        assert_eq!(report.summary.circular_references, 1);
+        assert_eq!(report.errors[0].target_reference, "b → a");
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_baseline_reports_only_new_errors() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        let baseline_path = temp_dir.path().join("baseline.json");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("legacy.md"), "---\ntitle: Legacy\n---\n[[gone]] and [[also-gone]]\n")?;
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        ValidationBaseline::from_report(&report).save(&baseline_path)?;
+        
+        // A new broken link is reported; the known ones are not
+        fs::write(articles_dir.join("legacy.md"), "---\ntitle: Legacy\n---\nNew [[missing]]\n\n[[gone]]\n")?;
+        let mut report = tool.validate_all_articles()?;
+        let mut baseline = ValidationBaseline::load(&baseline_path)?;
+        assert_eq!(baseline.filter_report(&mut report), 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].target_reference, "missing");
+        assert_eq!(report.summary.broken_links, 1);
+        assert_eq!(report.summary.total_errors, 1);
+        
+        // [[also-gone]] was fixed, so pruning drops it
+        let report = tool.validate_all_articles()?;
+        let resolved = baseline.prune(&report);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].target_reference, "also-gone");
+        assert_eq!(baseline.entries.len(), 1);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_fix_rewrites_confident_suggestions_only() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("tokio-basics.md"), "---\ntitle: Tokio\n---\n# Tokio\n")?;
+        let source = "---\ntitle:   \"Intro\"\nrelated_articles: [\"tokio-basic\", \"unrelated\"]\n---\n\nSee [[Tokio Basic#setup|the basics]],  [tokio](tokio-basic) and [[zzz]].\r\n";
+        fs::write(articles_dir.join("intro.md"), source)?;
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir, false, false)?;
+        assert_eq!(tool.fix_articles(true)?, 1);
+        assert_eq!(fs::read_to_string(articles_dir.join("intro.md"))?, source);
+        
+        assert_eq!(tool.fix_articles(false)?, 1);
+        assert_eq!(
+            fs::read_to_string(articles_dir.join("intro.md"))?,
+            "---\ntitle:   \"Intro\"\nrelated_articles: [\"tokio-basics\", \"unrelated\"]\n---\n\nSee [[tokio-basics#setup|the basics]],  [tokio](tokio-basics) and [[zzz]].\r\n"
+        );
+        
+        let report = tool.validate_all_articles()?;
+        let remaining: Vec<_> = report.errors.iter().map(|e| e.target_reference.as_str()).collect();
+        assert!(remaining.contains(&"zzz"));
+        assert!(remaining.contains(&"unrelated"));
+        assert!(!remaining.contains(&"tokio-basic"));
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_fix_toml_front_matter() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("tokio-basics.md"), "+++\ntitle = \"Tokio\"\n+++\n# Tokio\n")?;
+        fs::write(articles_dir.join("intro.md"), "+++\ntitle = \"Intro\"\nrelated_articles = [\n  \"tokio-basic\",\n]\n+++\n\nSee [[tokio-basic]].\n")?;
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        let broken: Vec<_> = report.errors.iter()
+            .filter(|e| e.error_type == ValidationErrorType::BrokenLink)
+            .map(|e| (e.target_reference.as_str(), e.line_number))
+            .collect();
+        assert_eq!(broken, vec![("tokio-basic", Some(8))]);
+        
+        assert_eq!(tool.fix_articles(false)?, 1);
+        assert_eq!(
+            fs::read_to_string(articles_dir.join("intro.md"))?,
+            "+++\ntitle = \"Intro\"\nrelated_articles = [\n  \"tokio-basics\",\n]\n+++\n\nSee [[tokio-basics]].\n"
+        );
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_external_links() -> Result<()> {
+        use khimoo_portfolio::external_links::{ExternalLinkOptions, HttpClient};
+        
+        struct FakeClient;
+        impl HttpClient for FakeClient {
+            fn status(&self, url: &str) -> Result<u16> {
+                Ok(if url.ends_with("/gone") { 404 } else { 200 })
+            }
+        }
+        
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(
+            articles_dir.join("links.md"),
+            "---\ntitle: Links\n---\n[ok](https://example.com/ok)\n\n[gone](https://example.com/gone)\n",
+        )?;
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir.clone(), false, false)?;
+        assert_eq!(tool.validate_all_articles()?.summary.broken_external_links, 0);
+        
+        let checker = ExternalLinkChecker::new(Box::new(FakeClient), ExternalLinkOptions::default());
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?.with_external_checker(checker);
+        let report = tool.validate_all_articles()?;
+        assert_eq!(report.summary.broken_external_links, 1);
+        let error = &report.errors[0];
+        assert_eq!(error.target_reference, "https://example.com/gone");
+        assert_eq!(error.context.as_deref(), Some("HTTP 404"));
+        assert_eq!((error.line_number, error.column_number), (Some(6), Some(1)));
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_images_are_reported() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(articles_dir.join("img"))?;
+        fs::write(articles_dir.join("img/present.png"), b"not checked")?;
+        fs::write(
+            articles_dir.join("gallery.md"),
+            "---\ntitle: Gallery\nauthor_image: img/author.png\n---\n![ok](img/present.png)\n\n![gone](img/gone.png)\n\n![remote](https://example.com/x.png)\n",
+        )?;
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        assert_eq!(report.summary.missing_assets, 2);
+        
+        let mut missing: Vec<_> = report.errors.iter()
+            .map(|e| (e.target_reference.as_str(), e.line_number, e.column_number))
+            .collect();
+        missing.sort();
+        assert_eq!(missing, vec![("img/author.png", None, None), ("img/gone.png", Some(7), Some(1))]);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_images_outside_articles_are_reported() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(temp_dir.path().join("Cargo.lock"), "# private")?;
+        fs::write(
+            articles_dir.join("leak.md"),
+            "---\ntitle: Leak\nauthor_image: ../Cargo.lock\n---\n![key](../../.ssh/id_rsa.png)\n",
+        )?;
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        assert_eq!(report.summary.missing_assets, 0);
+        assert_eq!(report.summary.total_errors, 2);
+        
+        let mut outside: Vec<_> = report.errors.iter()
+            .map(|e| (e.error_type.clone(), e.target_reference.as_str(), e.line_number))
+            .collect();
+        outside.sort();
+        assert_eq!(outside, vec![
+            (ValidationErrorType::AssetOutsideArticles, "../../.ssh/id_rsa.png", Some(5)),
+            (ValidationErrorType::AssetOutsideArticles, "../Cargo.lock", None),
+        ]);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_front_matter_errors() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("typo.md"), "---\ntitle: Typo\nhome_dispaly: true\n---\nSee [[broken]].\n")?;
+        fs::write(articles_dir.join("bad-type.md"), "---\ntitle: Bad\nimportance: high\n---\n")?;
+        
+        // Leniently, the typo is ignored and the bad type fails the run
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir.clone(), false, false)?;
+        assert!(tool.validate_all_articles().is_err());
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?.with_strict_front_matter(true);
+        let report = tool.validate_all_articles()?;
+        let mut invalid: Vec<_> = report.errors.iter()
+            .filter(|e| e.error_type == ValidationErrorType::InvalidMetadata)
+            .map(|e| (e.source_article.as_str(), e.target_reference.as_str(), e.line_number))
+            .collect();
+        invalid.sort();
+        assert_eq!(invalid, vec![("bad-type", "importance", Some(3)), ("typo", "home_dispaly", Some(3))]);
+        // The article that still parses is validated as usual
+        assert_eq!(report.summary.broken_links, 1);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_links_to_drafts_are_warned() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("home.md"), "---\ntitle: Home\nhome_display: true\n---\nSee [[draft]], [[draft]] and [[later]].\n")?;
+        fs::write(articles_dir.join("draft.md"), "---\ntitle: Draft\ndraft: true\n---\nSee [[later]].\n")?;
+        fs::write(articles_dir.join("later.md"), "---\ntitle: Later\npublish_at: 2999-01-01\n---\nSee [[home]].\n")?;
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        let drafts: Vec<_> = report.warnings.iter()
+            .filter(|w| w.warning_type == ValidationWarningType::LinkToDraft)
+            .map(|w| (w.source_article.as_str(), w.target_reference.as_deref().unwrap(), w.context.as_deref().unwrap()))
+            .collect();
+        // Unpublished articles may link to each other
+        assert_eq!(drafts, vec![
+            ("home", "draft", "Target is a draft"),
+            ("home", "later", "Target is scheduled for 2999-01-01 00:00 UTC"),
+        ]);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_only_summary_counts_every_error() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let articles_dir = temp_dir.path().join("articles");
+        let output_dir = temp_dir.path().join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("home.md"), "---\ntitle: Home\nhome_display: true\n---\nNo links.\n")?;
+        fs::write(articles_dir.join("island.md"), "---\ntitle: Island\n---\nNo links.\n")?;
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?;
+        let report = tool.validate_all_articles()?;
+        assert_eq!(report.summary.broken_links, 0);
+        
+        let output = tool.format_console_errors_only(&report);
+        assert!(output.contains("❌ Errors: 1\n"), "{}", output);
+        assert!(output.contains("Orphaned articles: 1\n"), "{}", output);
+        assert!(!output.contains("✅"), "{}", output);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_exit_code_policy() {
+        let summary = |total_errors, total_warnings| ValidationSummary {
+            total_articles: 1,
+            total_links: 0,
+            broken_links: 0,
+            invalid_references: 0,
+            orphaned_articles: 0,
+            circular_references: 0,
+            broken_external_links: 0,
+            missing_assets: 0,
+            articles_with_errors: 0,
+            articles_with_warnings: 0,
+            total_errors,
+            total_warnings,
+        };
+        
+        assert_eq!(exit_code(&summary(0, 0), FailOn::Warning, Some(0)), EXIT_OK);
+        assert_eq!(exit_code(&summary(1, 0), FailOn::Error, None), EXIT_ERRORS);
+        assert_eq!(exit_code(&summary(1, 3), FailOn::Warning, None), EXIT_ERRORS);
+        assert_eq!(exit_code(&summary(1, 0), FailOn::Never, None), EXIT_OK);
+        assert_eq!(exit_code(&summary(0, 3), FailOn::Error, None), EXIT_OK);
+        assert_eq!(exit_code(&summary(0, 3), FailOn::Warning, None), EXIT_WARNINGS);
+        assert_eq!(exit_code(&summary(0, 3), FailOn::Error, Some(3)), EXIT_OK);
+        assert_eq!(exit_code(&summary(0, 3), FailOn::Never, Some(2)), EXIT_WARNINGS);
+    }
+
     #[test]
     fn test_output_formats() -> Result<()> {
         // Create minimal test setup
@@ -433,7 +1026,7 @@ This links back to [Article 1](article1).
             "---\ntitle: Test\n---\n# Test\n",
         )?;
         
-        let tool = LinkValidationTool::new(articles_dir, output_dir.clone(), false, false);
+        let tool = LinkValidationTool::new(articles_dir, output_dir.clone(), false, false)?;
         let report = tool.validate_all_articles()?;
         
         // Test JSON format
@@ -442,6 +1035,10 @@ This links back to [Article 1](article1).
         // Test CI format
         tool.output_report(&report, "ci", false)?;
         
+        // Test SARIF and JUnit formats
+        tool.output_report(&report, "sarif", false)?;
+        tool.output_report(&report, "junit", false)?;
+        
         // Test console format with file writing
         tool.output_report(&report, "console", true)?;
         
@@ -452,4 +1049,33 @@ This links back to [Article 1](article1).
         
         Ok(())
     }
+    
+    #[test]
+    fn test_sarif_paths_are_relative_to_the_repository_root() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        git2::Repository::init(temp_dir.path())?;
+        let site_dir = temp_dir.path().join("site");
+        let articles_dir = site_dir.join("articles");
+        let output_dir = site_dir.join("data");
+        
+        fs::create_dir_all(&articles_dir)?;
+        fs::write(articles_dir.join("broken.md"), "---\ntitle: Broken\nhome_display: true\n---\n\nSee [[missing]].\n")?;
+        
+        let location = |tool: &LinkValidationTool| -> Result<serde_json::Value> {
+            let report = tool.validate_all_articles()?;
+            let sarif: serde_json::Value = serde_json::from_str(&tool.format_sarif(&report)?)?;
+            Ok(sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"].clone())
+        };
+        
+        let tool = LinkValidationTool::new(articles_dir.clone(), output_dir.clone(), false, false)?;
+        let physical = location(&tool)?;
+        assert_eq!(physical["artifactLocation"]["uri"], "site/articles/broken.md");
+        assert_eq!(physical["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(physical["region"]["startLine"], 6);
+        
+        let tool = LinkValidationTool::new(articles_dir, output_dir, false, false)?.with_sarif_root(Some(site_dir));
+        assert_eq!(location(&tool)?["artifactLocation"]["uri"], "articles/broken.md");
+        
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,344 @@
+//! Checking of external `http(s)://` links for `validate-links --check-external`.
+//!
+//! Requests go through the [`HttpClient`] trait so tests can substitute a
+//! fake client or point the real one at a local server. Results are kept in
+//! a persistent [`ExternalLinkCache`] so repeated runs don't hit every URL.
+
+use crate::article_processing::{write_atomically, ExternalLink, ProcessedArticle};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Bump when the cache file layout changes
+const EXTERNAL_LINK_CACHE_VERSION: u32 = 1;
+
+/// Longest wait between two attempts, however many retries are configured
+pub const MAX_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Default location of the result cache, relative to the working directory
+pub const EXTERNAL_LINK_CACHE_FILE: &str = ".external-link-cache.json";
+
+/// External link checking settings, read from `[validation.external]`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExternalLinkOptions {
+    /// Most requests in flight at once
+    pub concurrency: usize,
+    /// Extra attempts after a network error, 429 or 5xx response
+    pub retries: u32,
+    /// Delay before the first retry; doubles on each further retry, up to
+    /// [`MAX_RETRY_DELAY`]
+    pub retry_delay_ms: u64,
+    pub timeout_secs: u64,
+    /// How long a cached result is trusted
+    pub cache_ttl_hours: i64,
+    /// URL prefixes that are never checked
+    pub ignore: Vec<String>,
+}
+
+impl Default for ExternalLinkOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            retries: 2,
+            retry_delay_ms: 500,
+            timeout_secs: 10,
+            cache_ttl_hours: 24,
+            ignore: Vec::new(),
+        }
+    }
+}
+
+impl ExternalLinkOptions {
+    /// Wait before retry number `attempt + 1`
+    pub fn retry_delay(&self, attempt: u32) -> std::time::Duration {
+        let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+        std::time::Duration::from_millis(self.retry_delay_ms.saturating_mul(factor)).min(MAX_RETRY_DELAY)
+    }
+}
+
+/// Outcome of checking one URL
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExternalLinkStatus {
+    Ok { status: u16 },
+    HttpError { status: u16 },
+    Unreachable { reason: String },
+}
+
+impl ExternalLinkStatus {
+    fn from_status(status: u16) -> Self {
+        if status < 400 {
+            ExternalLinkStatus::Ok { status }
+        } else {
+            ExternalLinkStatus::HttpError { status }
+        }
+    }
+
+    pub fn is_broken(&self) -> bool {
+        !matches!(self, ExternalLinkStatus::Ok { .. })
+    }
+
+    /// Whether the failure may go away on its own, so it is retried and
+    /// not cached
+    fn is_transient(&self) -> bool {
+        match self {
+            ExternalLinkStatus::Ok { .. } => false,
+            ExternalLinkStatus::HttpError { status } => *status == 429 || *status >= 500,
+            ExternalLinkStatus::Unreachable { .. } => true,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            ExternalLinkStatus::Ok { status } | ExternalLinkStatus::HttpError { status } => format!("HTTP {}", status),
+            ExternalLinkStatus::Unreachable { reason } => format!("unreachable: {}", reason),
+        }
+    }
+}
+
+/// Fetches the response status of a URL
+pub trait HttpClient: Send + Sync {
+    /// Final status code after redirects, or an error when no response
+    /// was received (DNS failure, refused connection, timeout)
+    fn status(&self, url: &str) -> Result<u16>;
+}
+
+/// [`HttpClient`] backed by `ureq`. Sends `HEAD` first and falls back to
+/// `GET` for servers that don't support it.
+#[cfg(feature = "cli-tools")]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "cli-tools")]
+impl UreqClient {
+    pub fn new(timeout: std::time::Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(timeout)
+                .user_agent(concat!("khimoo-portfolio-validate-links/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    fn request(&self, method: &str, url: &str) -> Result<u16> {
+        match self.agent.request(method, url).call() {
+            Ok(response) => Ok(response.status()),
+            Err(ureq::Error::Status(status, _)) => Ok(status),
+            Err(e) => Err(anyhow::anyhow!(e)),
+        }
+    }
+}
+
+#[cfg(feature = "cli-tools")]
+impl HttpClient for UreqClient {
+    fn status(&self, url: &str) -> Result<u16> {
+        match self.request("HEAD", url)? {
+            405 | 501 => self.request("GET", url),
+            status => Ok(status),
+        }
+    }
+}
+
+/// Persistent check results keyed by URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalLinkCache {
+    cache_version: u32,
+    entries: BTreeMap<String, CachedLinkStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedLinkStatus {
+    pub checked_at: DateTime<Utc>,
+    pub status: ExternalLinkStatus,
+}
+
+impl Default for ExternalLinkCache {
+    fn default() -> Self {
+        Self {
+            cache_version: EXTERNAL_LINK_CACHE_VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl ExternalLinkCache {
+    /// Load a cache file. A missing file or one written by an incompatible
+    /// version yields an empty cache.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read external link cache from {:?}", path))?;
+        let cache: Self = serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse external link cache {:?}", path))?;
+
+        if cache.cache_version != EXTERNAL_LINK_CACHE_VERSION {
+            return Ok(Self::default());
+        }
+        Ok(cache)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .context("Failed to serialize external link cache")?;
+        write_atomically(path, &json)
+            .with_context(|| format!("Failed to write external link cache to {:?}", path))
+    }
+
+    /// Cached result for `url` if it was checked less than `ttl` before `now`
+    pub fn fresh(&self, url: &str, ttl: Duration, now: DateTime<Utc>) -> Option<&ExternalLinkStatus> {
+        self.entries
+            .get(url)
+            .filter(|cached| now - cached.checked_at < ttl)
+            .map(|cached| &cached.status)
+    }
+
+    pub fn insert(&mut self, url: String, status: ExternalLinkStatus, checked_at: DateTime<Utc>) {
+        self.entries.insert(url, CachedLinkStatus { checked_at, status });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// An external link whose check failed
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenExternalLink {
+    pub source_article: String,
+    pub file_path: String,
+    pub link: ExternalLink,
+    pub status: ExternalLinkStatus,
+}
+
+/// Checks the external links of a set of articles
+pub struct ExternalLinkChecker {
+    client: Box<dyn HttpClient>,
+    options: ExternalLinkOptions,
+    cache: Mutex<ExternalLinkCache>,
+    cache_file: Option<PathBuf>,
+}
+
+impl ExternalLinkChecker {
+    pub fn new(client: Box<dyn HttpClient>, options: ExternalLinkOptions) -> Self {
+        Self {
+            client,
+            options,
+            cache: Mutex::new(ExternalLinkCache::default()),
+            cache_file: None,
+        }
+    }
+
+    /// Reuse results from `cache_file` and write new ones back after each
+    /// check. A cache that can't be read is ignored.
+    pub fn with_cache_file(mut self, cache_file: PathBuf) -> Self {
+        let cache = ExternalLinkCache::load(&cache_file).unwrap_or_else(|e| {
+            eprintln!("⚠️  Ignoring external link cache: {:#}", e);
+            ExternalLinkCache::default()
+        });
+        self.cache = Mutex::new(cache);
+        self.cache_file = Some(cache_file);
+        self
+    }
+
+    /// Check every distinct external URL in `articles` and return the
+    /// links that are broken
+    pub fn check_articles(&self, articles: &[ProcessedArticle]) -> Result<Vec<BrokenExternalLink>> {
+        let urls: BTreeSet<&str> = articles
+            .iter()
+            .flat_map(|article| &article.external_links)
+            .map(|link| link.url.as_str())
+            .filter(|url| !self.is_ignored(url))
+            .collect();
+        let results = self.check_urls(urls)?;
+
+        let mut broken = Vec::new();
+        for article in articles {
+            for link in &article.external_links {
+                if let Some(status) = results.get(link.url.as_str()).filter(|status| status.is_broken()) {
+                    broken.push(BrokenExternalLink {
+                        source_article: article.slug.clone(),
+                        file_path: article.file_path.clone(),
+                        link: link.clone(),
+                        status: status.clone(),
+                    });
+                }
+            }
+        }
+        Ok(broken)
+    }
+
+    /// Status of each URL, from the cache when fresh and otherwise from
+    /// at most `concurrency` parallel requests
+    pub fn check_urls<'a>(&self, urls: impl IntoIterator<Item = &'a str>) -> Result<BTreeMap<&'a str, ExternalLinkStatus>> {
+        let now = Utc::now();
+        let ttl = Duration::hours(self.options.cache_ttl_hours);
+        let mut results = BTreeMap::new();
+        let mut pending = Vec::new();
+        {
+            let cache = self.cache.lock().unwrap();
+            for url in urls {
+                match cache.fresh(url, ttl, now) {
+                    Some(status) => {
+                        results.insert(url, status.clone());
+                    }
+                    None => pending.push(url),
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(self.options.concurrency.max(1))
+                .build()
+                .context("Failed to start external link check threads")?;
+            let checked: Vec<(&str, ExternalLinkStatus)> = pool.install(|| {
+                use rayon::prelude::*;
+                pending.par_iter().map(|url| (*url, self.check_with_retries(url))).collect()
+            });
+
+            let mut cache = self.cache.lock().unwrap();
+            for (url, status) in checked {
+                if !status.is_transient() {
+                    cache.insert(url.to_string(), status.clone(), now);
+                }
+                results.insert(url, status);
+            }
+            if let Some(cache_file) = &self.cache_file {
+                cache.save(cache_file)?;
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn check_with_retries(&self, url: &str) -> ExternalLinkStatus {
+        let mut attempt = 0;
+        loop {
+            let status = match self.client.status(url) {
+                Ok(status) => ExternalLinkStatus::from_status(status),
+                Err(e) => ExternalLinkStatus::Unreachable { reason: format!("{:#}", e) },
+            };
+            if !status.is_transient() || attempt >= self.options.retries {
+                return status;
+            }
+            std::thread::sleep(self.options.retry_delay(attempt));
+            attempt += 1;
+        }
+    }
+
+    fn is_ignored(&self, url: &str) -> bool {
+        self.options.ignore.iter().any(|prefix| url.starts_with(prefix.as_str()))
+    }
+}
@@ -0,0 +1,145 @@
+//! Article dates from git history for `process-articles --git-dates`.
+//!
+//! The history is read once through libgit2: every commit reachable from
+//! `HEAD` is diffed against its first parent, and the author times of the
+//! first and last commit touching each file are kept. Renames are not
+//! followed, so a moved article starts over at the commit that moved it.
+
+use crate::article_processing::{DateSource, ProcessedArticle};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use git2::{DiffOptions, Repository};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Working directory of the repository containing `dir`, if any
+pub fn repository_root(dir: &Path) -> Option<PathBuf> {
+    let repo = Repository::discover(dir).ok()?;
+    repo.workdir()?.canonicalize().ok()
+}
+
+/// Author times of the first and last commit touching a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileDates {
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Commit dates of the files under a directory of a local repository
+pub struct GitDates {
+    /// Canonical working directory of the repository
+    workdir: PathBuf,
+    /// Keyed by path relative to `workdir`
+    files: HashMap<PathBuf, FileDates>,
+    shallow: bool,
+}
+
+impl GitDates {
+    /// Read the history of the repository containing `dir`, limited to
+    /// files under `dir`
+    pub fn open(dir: &Path) -> Result<Self> {
+        let repo = Repository::discover(dir)
+            .with_context(|| format!("No git repository found for {:?}", dir))?;
+        let workdir = repo
+            .workdir()
+            .context("Git dates need a repository with a working directory")?
+            .canonicalize()
+            .context("Failed to resolve the repository working directory")?;
+        let dir = dir
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {:?}", dir))?;
+        let pathspec = dir.strip_prefix(&workdir).unwrap_or(Path::new("")).to_path_buf();
+
+        let files = if repo.head().is_ok() {
+            Self::read_history(&repo, &pathspec)?
+        } else {
+            // No commits yet
+            HashMap::new()
+        };
+
+        Ok(Self {
+            workdir,
+            files,
+            shallow: repo.is_shallow(),
+        })
+    }
+
+    fn read_history(repo: &Repository, pathspec: &Path) -> Result<HashMap<PathBuf, FileDates>> {
+        let mut revwalk = repo.revwalk().context("Failed to walk the git history")?;
+        revwalk.push_head().context("Failed to read HEAD")?;
+
+        let mut files: HashMap<PathBuf, FileDates> = HashMap::new();
+        for oid in revwalk {
+            let commit = repo.find_commit(oid?)?;
+            let author_time = commit.author().when();
+            let Some(time) = DateTime::from_timestamp(author_time.seconds(), 0) else {
+                continue;
+            };
+
+            let tree = commit.tree()?;
+            let parent_tree = match commit.parents().next() {
+                Some(parent) => Some(parent.tree()?),
+                None => None,
+            };
+            let mut options = DiffOptions::new();
+            if !pathspec.as_os_str().is_empty() {
+                options.pathspec(pathspec);
+            }
+            let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options))?;
+
+            for delta in diff.deltas() {
+                let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                    continue;
+                };
+                files
+                    .entry(path.to_path_buf())
+                    .and_modify(|dates| {
+                        dates.created_at = dates.created_at.min(time);
+                        dates.updated_at = dates.updated_at.max(time);
+                    })
+                    .or_insert(FileDates { created_at: time, updated_at: time });
+            }
+        }
+        Ok(files)
+    }
+
+    /// In a shallow clone the oldest fetched commit looks like the one
+    /// that created every file, so `created_at` can't be trusted
+    pub fn is_shallow(&self) -> bool {
+        self.shallow
+    }
+
+    /// Dates of `file`, or `None` when it was never committed
+    pub fn dates(&self, file: &Path) -> Option<FileDates> {
+        let file = file.canonicalize().ok()?;
+        self.files.get(file.strip_prefix(&self.workdir).ok()?).copied()
+    }
+
+    /// Fill `created_at`/`updated_at` that aren't set in front matter and
+    /// record the source. Returns the number of articles changed.
+    pub fn fill_missing_dates(&self, articles: &mut [ProcessedArticle]) -> usize {
+        let mut filled = 0;
+        for article in articles.iter_mut() {
+            let Some(dates) = self.dates(Path::new(&article.file_path)) else {
+                continue;
+            };
+            let metadata = &mut article.metadata;
+            let sources = &mut article.date_sources;
+            let mut changed = false;
+            if metadata.created_at.is_none() {
+                metadata.created_at = Some(dates.created_at);
+                sources.created_at = Some(DateSource::Git);
+                changed = true;
+            }
+            if metadata.updated_at.is_none() {
+                metadata.updated_at = Some(dates.updated_at);
+                sources.updated_at = Some(DateSource::Git);
+                changed = true;
+            }
+            if changed {
+                filled += 1;
+            }
+        }
+        filled
+    }
+}
@@ -1,7 +1,9 @@
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
-use pulldown_cmark::{html, Parser};
 use yew::virtual_dom::AttrValue;
 use super::data_loader::{use_article_content, use_lightweight_articles};
+use crate::schema::{DateSource, ASSET_URL_PREFIX};
+use chrono::{DateTime, Utc};
 use yew_router::prelude::*;
 
 #[function_component(ArticleIndex)]
@@ -101,6 +103,41 @@ pub struct ArticleViewProps {
 #[function_component(ArticleView)]
 pub fn article_view(props: &ArticleViewProps) -> Html {
     let (article, loading, error) = use_article_content(Some(props.slug.clone()));
+    let navigator = use_navigator();
+    
+    // Article links in the pre-rendered HTML carry `data-article-slug`;
+    // navigate through the router instead of reloading the page
+    let on_content_click = {
+        let navigator = navigator.clone();
+        Callback::from(move |e: MouseEvent| {
+            if e.ctrl_key() || e.meta_key() || e.shift_key() || e.button() != 0 {
+                return; // Let the browser open a new tab/window
+            }
+            let anchor = e
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|element| element.closest("a[data-article-slug]").ok().flatten());
+            let slug = anchor.as_ref().and_then(|anchor| anchor.get_attribute("data-article-slug"));
+            if let (Some(slug), Some(navigator)) = (slug, navigator.as_ref()) {
+                e.prevent_default();
+                navigator.push(&Route::ArticleShow { slug });
+                if let Some(section) = anchor.and_then(|anchor| anchor.get_attribute("data-article-section")) {
+                    set_location_hash(&section);
+                    scroll_to_location_hash();
+                }
+            }
+        })
+    };
+    
+    // Scroll to the `#section` of the URL once the article has rendered
+    {
+        let loaded_slug = article.as_ref().map(|a| a.slug.clone());
+        use_effect_with(loaded_slug, |loaded_slug| {
+            if loaded_slug.is_some() {
+                scroll_to_location_hash();
+            }
+        });
+    }
     
     if *loading {
         return html! {
@@ -138,17 +175,16 @@ pub fn article_view(props: &ArticleViewProps) -> Html {
     }
     
     if let Some(article_data) = article.as_ref() {
-        // Convert markdown to HTML
-        let parser = Parser::new(&article_data.content);
-        let mut html_output = String::new();
-        html::push_html(&mut html_output, parser);
+        // HTML is rendered and sanitized by process-articles at build time
+        let basename = navigator.as_ref().and_then(|n| n.basename().map(str::to_string));
+        let html_output = with_basename(&article_data.html, basename.as_deref());
         let rendered = Html::from_html_unchecked(AttrValue::from(html_output));
         
         html! {
             <>
                 <style>
                     {"@keyframes spin { 0% { transform: rotate(0deg); } 100% { transform: rotate(360deg); } }"}
-                    {".markdown-body { line-height: 1.6; } .markdown-body h1, .markdown-body h2, .markdown-body h3 { margin-top: 24px; margin-bottom: 16px; } .markdown-body p { margin-bottom: 16px; } .markdown-body ul, .markdown-body ol { margin-bottom: 16px; padding-left: 30px; } .markdown-body code { background: #f6f8fa; padding: 2px 4px; border-radius: 3px; font-size: 85%; } .markdown-body pre { background: #f6f8fa; padding: 16px; border-radius: 6px; overflow: auto; } .markdown-body blockquote { border-left: 4px solid #dfe2e5; padding-left: 16px; color: #6a737d; margin: 0 0 16px 0; }"}
+                    {".markdown-body { line-height: 1.6; } .markdown-body h1, .markdown-body h2, .markdown-body h3 { margin-top: 24px; margin-bottom: 16px; } .markdown-body p { margin-bottom: 16px; } .markdown-body ul, .markdown-body ol { margin-bottom: 16px; padding-left: 30px; } .markdown-body code { background: #f6f8fa; padding: 2px 4px; border-radius: 3px; font-size: 85%; } .markdown-body pre { background: #f6f8fa; padding: 16px; border-radius: 6px; overflow: auto; } .markdown-body blockquote { border-left: 4px solid #dfe2e5; padding-left: 16px; color: #6a737d; margin: 0 0 16px 0; } .markdown-body a.article-link { color: #007bff; } .markdown-body a.broken-link { color: #d73a49; text-decoration: underline dotted; cursor: help; }"}
                 </style>
                 <div style="padding: 16px; max-width: 800px; margin: 0 auto;">
                     <div style="margin-bottom: 20px; display: flex; justify-content: space-between; align-items: center;">
@@ -175,14 +211,18 @@ pub fn article_view(props: &ArticleViewProps) -> Html {
                                         html! {}
                                     }
                                 }
+                                <span>{"Importance: "}<strong>{article_data.metadata.importance}{"/5"}</strong></span>
+                                <span>{"Inbound links: "}<strong>{article_data.inbound_count}</strong></span>
+                                { date_label("Created", article_data.metadata.created_at, article_data.date_sources.created_at) }
+                                { date_label("Updated", article_data.metadata.updated_at, article_data.date_sources.updated_at) }
                                 {
-                                    if let Some(importance) = article_data.metadata.importance {
-                                        html! { <span>{"Importance: "}<strong>{importance}{"/5"}</strong></span> }
+                                    // Only built with process-articles --include-drafts
+                                    if article_data.metadata.draft {
+                                        html! { <span><strong>{"Draft"}</strong></span> }
                                     } else {
                                         html! {}
                                     }
                                 }
-                                <span>{"Inbound links: "}<strong>{article_data.inbound_count}</strong></span>
                                 {
                                     if !article_data.metadata.tags.is_empty() {
                                         html! {
@@ -207,7 +247,7 @@ pub fn article_view(props: &ArticleViewProps) -> Html {
                             </div>
                         </header>
                         
-                        <div class="markdown-body">
+                        <div class="markdown-body" onclick={on_content_click}>
                             { rendered }
                         </div>
                         
@@ -262,3 +302,52 @@ pub fn article_view(props: &ArticleViewProps) -> Html {
         }
     }
 }
+
+// "Created: 2024-01-15", with a tooltip when the date comes from the git history
+fn date_label(label: &str, date: Option<DateTime<Utc>>, source: Option<DateSource>) -> Html {
+    match date {
+        Some(date) => {
+            let title = (source == Some(DateSource::Git)).then_some("From git history");
+            html! {
+                <span title={title}>{format!("{}: ", label)}<strong>{date.format("%Y-%m-%d").to_string()}</strong></span>
+            }
+        }
+        None => html! {},
+    }
+}
+
+// Article links and published images are rendered relative to the site root;
+// prefix them with the router basename (e.g. /khimoo.io) so they also work
+// when opened directly
+fn with_basename(html: &str, basename: Option<&str>) -> String {
+    match basename {
+        Some(base) if !base.is_empty() && base != "/" => {
+            let base = base.trim_end_matches('/');
+            html.replace("href=\"/article/", &format!("href=\"{}/article/", base))
+                .replace(&format!("\"{}", ASSET_URL_PREFIX), &format!("\"{}{}", base, ASSET_URL_PREFIX))
+                .replace(&format!(", {}", ASSET_URL_PREFIX), &format!(", {}{}", base, ASSET_URL_PREFIX))
+        }
+        _ => html.to_string(),
+    }
+}
+
+// Replace the URL fragment without adding a history entry or notifying the router
+fn set_location_hash(section: &str) {
+    if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&format!("#{}", section)));
+    }
+}
+
+fn scroll_to_location_hash() {
+    let Some(window) = web_sys::window() else { return };
+    let Ok(hash) = window.location().hash() else { return };
+    let Some(encoded) = hash.strip_prefix('#').filter(|h| !h.is_empty()) else { return };
+    // Non-ASCII heading ids come back percent-encoded
+    let id = js_sys::decode_uri_component(encoded)
+        .ok()
+        .and_then(|id| id.as_string())
+        .unwrap_or_else(|| encoded.to_string());
+    if let Some(heading) = window.document().and_then(|d| d.get_element_by_id(&id)) {
+        heading.scroll_into_view();
+    }
+}
@@ -44,7 +44,7 @@ impl ArticleManager {
 
         // Load articles and create lightweight versions
         for article in articles_data.articles {
-            let lightweight = LightweightArticle::from(article.clone());
+            let lightweight = LightweightArticle::from(&article);
             self.lightweight_articles.insert(article.slug.clone(), lightweight);
             
             // Only keep full articles for home articles in memory initially
@@ -233,7 +233,7 @@ impl ArticleManager {
                 // Calculate node size dynamically based on importance and inbound links
                 let radius = registry.calculate_dynamic_radius(
                     node_id, 
-                    article.metadata.importance, 
+                    Some(article.metadata.importance), 
                     article.inbound_count
                 );
 
@@ -334,7 +334,7 @@ impl ArticleManager {
         // Add article nodes
         for (slug, node_id) in &self.slug_to_node_id {
             if let Some(article) = self.lightweight_articles.get(slug) {
-                sizing_data.insert(*node_id, (article.metadata.importance, article.inbound_count));
+                sizing_data.insert(*node_id, (Some(article.metadata.importance), article.inbound_count));
             }
         }
         
@@ -351,7 +351,7 @@ impl ArticleManager {
             if let Some(article) = self.lightweight_articles.get(slug) {
                 let new_radius = registry.calculate_dynamic_radius(
                     *node_id,
-                    article.metadata.importance,
+                    Some(article.metadata.importance),
                     article.inbound_count
                 );
                 registry.update_node_radius(*node_id, new_radius);
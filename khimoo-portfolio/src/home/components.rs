@@ -1,6 +1,8 @@
 use super::physics_sim::{PhysicsWorld, Viewport};
 use super::types::*;
-use super::data_loader::{use_articles_data, ArticlesData, ProcessedArticle};
+use super::data_loader::{use_article_index, ArticleIndex, LightweightArticle};
+#[cfg(target_arch = "wasm32")]
+use super::data_loader::DataLoader;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -23,7 +25,7 @@ enum Route {
 }
 
 // 記事の内容に基づいてNodeContentを決定する関数
-fn determine_node_content(article: &ProcessedArticle) -> NodeContent {
+fn determine_node_content(article: &LightweightArticle) -> NodeContent {
     if let Some(image_url) = &article.metadata.author_image {
         #[cfg(target_arch = "wasm32")]
         web_sys::console::log_1(&format!(
@@ -32,9 +34,15 @@ fn determine_node_content(article: &ProcessedArticle) -> NodeContent {
             image_url
         ).into());
 
+        // Local author images are published under /data at build time
+        #[cfg(target_arch = "wasm32")]
+        let image_url = DataLoader::new().resolve_data_url(image_url);
+        #[cfg(not(target_arch = "wasm32"))]
+        let image_url = image_url.clone();
+
         NodeContent::Author {
             name: article.title.clone(),
-            image_url: image_url.clone(),
+            image_url,
             bio: None,
         }
     } else {
@@ -42,8 +50,8 @@ fn determine_node_content(article: &ProcessedArticle) -> NodeContent {
     }
 }
 
-// ArticlesDataからNodeRegistryを生成する関数（統一処理版）
-fn create_node_registry_from_articles(articles_data: &ArticlesData, container_bound: &ContainerBound) -> (NodeRegistry, HashMap<NodeId, String>) {
+// ArticleIndexからNodeRegistryを生成する関数（統一処理版）
+fn create_node_registry_from_articles(articles_data: &ArticleIndex, container_bound: &ContainerBound) -> (NodeRegistry, HashMap<NodeId, String>) {
     let mut reg = NodeRegistry::new();
     let mut slug_to_id = HashMap::new();
     let mut id_to_slug = HashMap::new();
@@ -120,7 +128,7 @@ fn create_node_registry_from_articles(articles_data: &ArticlesData, container_bo
         reg.add_node(node_id, position, base_radius, content);
 
         // 重要度とリンク数を設定
-        reg.set_node_importance(node_id, article.metadata.importance.unwrap_or(3));
+        reg.set_node_importance(node_id, article.metadata.importance);
         reg.set_node_inbound_count(node_id, article.inbound_count);
 
         slug_to_id.insert(article.slug.clone(), node_id);
@@ -131,12 +139,12 @@ fn create_node_registry_from_articles(articles_data: &ArticlesData, container_bo
     // 記事間のリンクを追加（作者記事も含む）
     for article in &home_articles {
         if let Some(&from_id) = slug_to_id.get(&article.slug) {
-            for link in &article.outbound_links {
-                if let Some(&to_id) = slug_to_id.get(&link.target_slug) {
+            for target_slug in &article.outbound_targets {
+                if let Some(&to_id) = slug_to_id.get(target_slug) {
                     #[cfg(target_arch = "wasm32")]
                     web_sys::console::log_1(&format!(
                         "Adding edge: {} -> {} (IDs: {} -> {})",
-                        article.slug, link.target_slug, from_id.0, to_id.0
+                        article.slug, target_slug, from_id.0, to_id.0
                     ).into());
                     reg.add_edge(from_id, to_id);
                 }
@@ -161,7 +169,7 @@ pub fn node_graph_container(props: &NodeGraphContainerProps) -> Html {
     let force_settings = use_state(ForceSettings::default);
 
     // データローダーを使用して記事データを取得
-    let (articles_data, loading, error) = use_articles_data();
+    let (articles_data, loading, error) = use_article_index();
 
     // 記事データが読み込まれたらノードレジストリと物理世界を一度だけ初期化
     let node_registry = use_state(|| Rc::new(RefCell::new(NodeRegistry::new())));
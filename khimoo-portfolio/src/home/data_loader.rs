@@ -1,155 +1,17 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::Deserialize;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestInit, RequestMode, Response};
 use yew::prelude::*;
 
-// Data structures matching the generated JSON format
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ProcessedArticle {
-    pub slug: String,
-    pub title: String,
-    pub content: String,
-    pub metadata: ProcessedMetadata,
-    pub file_path: String,
-    pub outbound_links: Vec<ProcessedLink>,
-    pub inbound_count: usize,
-    pub processed_at: String,
-}
-
-// Lightweight article data for list display (without full content)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct LightweightArticle {
-    pub slug: String,
-    pub title: String,
-    pub summary: Option<String>, // First paragraph or excerpt
-    pub metadata: ProcessedMetadata,
-    pub file_path: String,
-    pub outbound_links: Vec<ProcessedLink>,
-    pub inbound_count: usize,
-    pub processed_at: String,
-}
-
-impl From<ProcessedArticle> for LightweightArticle {
-    fn from(article: ProcessedArticle) -> Self {
-        // Extract summary from content (first paragraph or first 200 characters)
-        let summary = extract_summary(&article.content);
-        
-        Self {
-            slug: article.slug,
-            title: article.title,
-            summary: Some(summary),
-            metadata: article.metadata,
-            file_path: article.file_path,
-            outbound_links: article.outbound_links,
-            inbound_count: article.inbound_count,
-            processed_at: article.processed_at,
-        }
-    }
-}
-
-// Extract summary from article content
-fn extract_summary(content: &str) -> String {
-    // Remove markdown headers and get first paragraph
-    let lines: Vec<&str> = content.lines().collect();
-    let mut summary_lines = Vec::new();
-    let mut found_content = false;
-    
-    for line in lines {
-        let trimmed = line.trim();
-        
-        // Skip empty lines and headers at the beginning
-        if trimmed.is_empty() || trimmed.starts_with('#') {
-            if found_content {
-                break; // Stop at first empty line or header after content
-            }
-            continue;
-        }
-        
-        found_content = true;
-        summary_lines.push(trimmed);
-        
-        // Stop after first paragraph or when we have enough content
-        if summary_lines.join(" ").len() > 200 {
-            break;
-        }
-    }
-    
-    let summary = summary_lines.join(" ");
-    if summary.len() > 200 {
-        format!("{}...", &summary[..197])
-    } else {
-        summary
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ProcessedMetadata {
-    pub title: String,
-    pub home_display: bool,
-    pub category: Option<String>,
-    pub importance: Option<u8>,
-    pub related_articles: Vec<String>,
-    pub tags: Vec<String>,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
-    pub author_image: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ProcessedLink {
-    pub target_slug: String,
-    pub link_type: LinkType,
-    pub context: String,
-    pub position: usize,
-    pub original_text: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum LinkType {
-    WikiLink,
-    MarkdownLink,
-    TagReference,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ArticlesData {
-    pub articles: Vec<ProcessedArticle>,
-    pub generated_at: String,
-    pub total_count: usize,
-    pub home_articles: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct LinkGraphData {
-    pub graph: HashMap<String, GraphNode>,
-    pub generated_at: String,
-    pub total_connections: usize,
-    pub bidirectional_pairs: Option<usize>,
-    pub direct_links: Option<usize>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct GraphNode {
-    pub connections: Vec<GraphConnection>,
-    pub inbound_count: usize,
-    pub outbound_count: Option<usize>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct GraphConnection {
-    pub target: String,
-    pub connection_type: ConnectionType,
-    pub bidirectional: bool,
-    pub link_count: Option<usize>,
-}
+use crate::schema::{check_schema_version, SchemaHeader, SchemaVersionMismatch};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ConnectionType {
-    DirectLink,
-    Bidirectional,
-}
+// Data structures of the generated JSON files, shared with the CLI tools
+pub use crate::schema::{
+    article_content_path, ArticleContentData, ArticleIndex, ArticleMetadata, ArticlesData,
+    ConnectionType, ExtractedLink, GraphConnection, GraphNode, LightweightArticle, LinkGraphData,
+    LinkType, ProcessedArticle, SCHEMA_VERSION,
+};
 
 // Error types for data loading
 #[derive(Debug, Clone, PartialEq)]
@@ -157,6 +19,7 @@ pub enum DataLoadError {
     NetworkError(String),
     ParseError(String),
     NotFound(String),
+    IncompatibleSchema(SchemaVersionMismatch),
 }
 
 impl std::fmt::Display for DataLoadError {
@@ -165,6 +28,7 @@ impl std::fmt::Display for DataLoadError {
             DataLoadError::NetworkError(msg) => write!(f, "Network error: {}", msg),
             DataLoadError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             DataLoadError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            DataLoadError::IncompatibleSchema(mismatch) => write!(f, "Incompatible data: {}", mismatch),
         }
     }
 }
@@ -187,7 +51,7 @@ impl DataLoader {
     // Detect the correct base URL based on the current window location
     fn detect_base_url() -> String {
         if let Some(window) = web_sys::window() {
-            if let Some(location) = window.location().pathname().ok() {
+            if let Ok(location) = window.location().pathname() {
                 web_sys::console::log_1(&format!("DataLoader: Current pathname: {}", location).into());
                 
                 // If we're in a subdirectory (like /khimoo.io/), use that as the base
@@ -198,7 +62,7 @@ impl DataLoader {
             }
             
             // Also check the hostname for additional context
-            if let Some(hostname) = window.location().hostname().ok() {
+            if let Ok(hostname) = window.location().hostname() {
                 web_sys::console::log_1(&format!("DataLoader: Current hostname: {}", hostname).into());
                 if hostname.contains("github.io") {
                     web_sys::console::log_1(&"DataLoader: Detected GitHub Pages, using /khimoo.io/data".into());
@@ -216,7 +80,15 @@ impl DataLoader {
         Self { base_url }
     }
 
-    // Load articles data with error handling and fallback
+    // Generated URLs under /data (e.g. published images) relative to the detected base URL
+    pub fn resolve_data_url(&self, url: &str) -> String {
+        match url.strip_prefix("/data/") {
+            Some(path) => format!("{}/{}", self.base_url, path),
+            None => url.to_string(),
+        }
+    }
+
+    // Load articles data, rejecting files written with another schema version
     pub async fn load_articles(&self) -> Result<ArticlesData, DataLoadError> {
         let url = format!("{}/articles.json", self.base_url);
         
@@ -224,40 +96,27 @@ impl DataLoader {
         web_sys::console::log_1(&format!("DataLoader: Attempting to load articles from: {}", url).into());
         web_sys::console::log_1(&format!("DataLoader: Base URL detected as: {}", self.base_url).into());
         
-        match self.fetch_json::<ArticlesData>(&url).await {
+        match self.fetch_versioned_json::<ArticlesData>(&url).await {
             Ok(data) => {
                 web_sys::console::log_1(&format!("DataLoader: Successfully loaded {} articles", data.articles.len()).into());
                 Ok(data)
             },
             Err(e) => {
-                web_sys::console::warn_1(&format!("Failed to load articles data: {}", e).into());
-                // Fallback to empty data structure
-                Ok(ArticlesData {
-                    articles: Vec::new(),
-                    generated_at: "1970-01-01T00:00:00Z".to_string(),
-                    total_count: 0,
-                    home_articles: Vec::new(),
-                })
+                web_sys::console::error_1(&format!("Failed to load articles data: {}", e).into());
+                Err(e)
             }
         }
     }
 
-    // Load link graph data with error handling and fallback
+    // Load link graph data, rejecting files written with another schema version
     pub async fn load_link_graph(&self) -> Result<LinkGraphData, DataLoadError> {
         let url = format!("{}/link-graph.json", self.base_url);
         
-        match self.fetch_json::<LinkGraphData>(&url).await {
+        match self.fetch_versioned_json::<LinkGraphData>(&url).await {
             Ok(data) => Ok(data),
             Err(e) => {
-                web_sys::console::warn_1(&format!("Failed to load link graph data: {}", e).into());
-                // Fallback to empty graph structure
-                Ok(LinkGraphData {
-                    graph: HashMap::new(),
-                    generated_at: "1970-01-01T00:00:00Z".to_string(),
-                    total_connections: 0,
-                    bidirectional_pairs: Some(0),
-                    direct_links: Some(0),
-                })
+                web_sys::console::error_1(&format!("Failed to load link graph data: {}", e).into());
+                Err(e)
             }
         }
     }
@@ -273,44 +132,64 @@ impl DataLoader {
         (articles_result, link_graph_result)
     }
 
+    // Load the article index (metadata and summaries, without article bodies)
+    pub async fn load_article_index(&self) -> Result<ArticleIndex, DataLoadError> {
+        let url = format!("{}/article-index.json", self.base_url);
+        
+        match self.fetch_versioned_json::<ArticleIndex>(&url).await {
+            Ok(data) => {
+                web_sys::console::log_1(&format!("DataLoader: Loaded index of {} articles", data.articles.len()).into());
+                Ok(data)
+            },
+            Err(e) => {
+                web_sys::console::error_1(&format!("Failed to load article index: {}", e).into());
+                Err(e)
+            }
+        }
+    }
+
     // Load lightweight articles data (without full content)
     pub async fn load_lightweight_articles(&self) -> Result<Vec<LightweightArticle>, DataLoadError> {
-        let articles_data = self.load_articles().await?;
-        let lightweight_articles = articles_data.articles
-            .into_iter()
-            .map(LightweightArticle::from)
-            .collect();
-        Ok(lightweight_articles)
+        let article_index = self.load_article_index().await?;
+        Ok(article_index.articles)
     }
 
     // Load full article content by slug
     pub async fn load_article_content(&self, slug: &str) -> Result<String, DataLoadError> {
-        // For now, we load from the full articles data
-        // In a more optimized implementation, we could have separate content files
-        let articles_data = self.load_articles().await?;
-        
-        articles_data.articles
-            .into_iter()
-            .find(|article| article.slug == slug)
-            .map(|article| article.content)
-            .ok_or_else(|| DataLoadError::NotFound(format!("Article not found: {}", slug)))
+        let article = self.load_article_by_slug(slug).await?;
+        Ok(article.content)
     }
 
-    // Load article by slug (full data)
+    // Load article by slug (full data) from its own content file
     pub async fn load_article_by_slug(&self, slug: &str) -> Result<ProcessedArticle, DataLoadError> {
-        let articles_data = self.load_articles().await?;
+        let url = format!("{}/{}", self.base_url, article_content_path(slug));
         
-        articles_data.articles
-            .into_iter()
-            .find(|article| article.slug == slug)
-            .ok_or_else(|| DataLoadError::NotFound(format!("Article not found: {}", slug)))
+        match self.fetch_versioned_json::<ArticleContentData>(&url).await {
+            Ok(data) => Ok(data.article),
+            Err(DataLoadError::NotFound(_)) => {
+                Err(DataLoadError::NotFound(format!("Article not found: {}", slug)))
+            }
+            Err(e) => Err(e),
+        }
     }
 
-    // Generic JSON fetching method
-    async fn fetch_json<T>(&self, url: &str) -> Result<T, DataLoadError>
+    // Fetch a generated data file and check its schema version before deserializing
+    async fn fetch_versioned_json<T>(&self, url: &str) -> Result<T, DataLoadError>
     where
         T: for<'de> Deserialize<'de>,
     {
+        let json = self.fetch_js_value(url).await?;
+
+        let header: SchemaHeader = serde_wasm_bindgen::from_value(json.clone())
+            .map_err(|e| DataLoadError::ParseError(format!("Failed to read schema version: {:?}", e)))?;
+        check_schema_version(header.schema_version).map_err(DataLoadError::IncompatibleSchema)?;
+
+        serde_wasm_bindgen::from_value(json)
+            .map_err(|e| DataLoadError::ParseError(format!("Failed to deserialize: {:?}", e)))
+    }
+
+    // Generic JSON fetching method
+    async fn fetch_js_value(&self, url: &str) -> Result<JsValue, DataLoadError> {
         let opts = RequestInit::new();
         opts.set_method("GET");
         opts.set_mode(RequestMode::Cors);
@@ -337,16 +216,11 @@ impl DataLoader {
             )));
         }
 
-        let json = JsFuture::from(resp.json().map_err(|e| {
+        JsFuture::from(resp.json().map_err(|e| {
             DataLoadError::ParseError(format!("Failed to get JSON: {:?}", e))
         })?)
         .await
-        .map_err(|e| DataLoadError::ParseError(format!("Failed to parse JSON: {:?}", e)))?;
-
-        let data: T = serde_wasm_bindgen::from_value(json)
-            .map_err(|e| DataLoadError::ParseError(format!("Failed to deserialize: {:?}", e)))?;
-
-        Ok(data)
+        .map_err(|e| DataLoadError::ParseError(format!("Failed to parse JSON: {:?}", e)))
     }
 }
 
@@ -362,9 +236,9 @@ pub fn use_data_loader() -> UseStateHandle<Option<DataLoader>> {
     use_state(|| Some(DataLoader::new()))
 }
 
-// Hook for loading articles data
+// Hook for loading the article index
 #[hook]
-pub fn use_articles_data() -> (UseStateHandle<Option<ArticlesData>>, UseStateHandle<bool>, UseStateHandle<Option<DataLoadError>>) {
+pub fn use_article_index() -> (UseStateHandle<Option<ArticleIndex>>, UseStateHandle<bool>, UseStateHandle<Option<DataLoadError>>) {
     let data = use_state(|| None);
     let loading = use_state(|| true);
     let error = use_state(|| None);
@@ -381,9 +255,9 @@ pub fn use_articles_data() -> (UseStateHandle<Option<ArticlesData>>, UseStateHan
             
             wasm_bindgen_futures::spawn_local(async move {
                 let loader = DataLoader::new();
-                match loader.load_articles().await {
-                    Ok(articles_data) => {
-                        data.set(Some(articles_data));
+                match loader.load_article_index().await {
+                    Ok(article_index) => {
+                        data.set(Some(article_index));
                         error.set(None);
                     }
                     Err(e) => {
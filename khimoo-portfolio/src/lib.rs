@@ -1,8 +1,15 @@
 pub mod home;
+pub mod schema;
 
 // Only include article_processing for non-WASM targets
 #[cfg(not(target_arch = "wasm32"))]
 pub mod article_processing;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod external_links;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod assets;
+#[cfg(all(not(target_arch = "wasm32"), feature = "cli-tools"))]
+pub mod git_dates;
 
 // Re-export commonly used types (only for non-WASM)
 #[cfg(not(target_arch = "wasm32"))]
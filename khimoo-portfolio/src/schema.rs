@@ -0,0 +1,471 @@
+//! Serialized data format shared by the CLI tools (writers) and the
+//! WASM frontend (reader). Compiled for both native and wasm32 targets,
+//! so it must only depend on serde and chrono.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+/// Version of the generated JSON format.
+/// Bump whenever a change to these types breaks older readers or writers.
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Per-article content files live under this directory of the data output
+pub const ARTICLE_CONTENT_DIR: &str = "articles";
+
+/// Path of an article's content file relative to the data directory
+pub fn article_content_path(slug: &str) -> String {
+    format!("{}/{}.json", ARTICLE_CONTENT_DIR, slug)
+}
+
+/// Published images live under this directory of the data output
+pub const ASSET_DIR: &str = "assets";
+
+/// URL prefix of published images. The frontend adds the router basename
+/// when the site isn't served from the root.
+pub const ASSET_URL_PREFIX: &str = "/data/assets/";
+
+/// Article metadata structure with default values
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleMetadata {
+    pub title: String,
+    #[serde(default)]
+    pub home_display: bool,
+    pub category: Option<String>,
+    #[serde(default = "default_importance")]
+    pub importance: u8,
+    #[serde(default)]
+    pub related_articles: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub updated_at: Option<DateTime<Utc>>,
+    pub author_image: Option<String>,
+    /// Validation rules (e.g. `unused-tag`, or `all`) not reported for this article
+    #[serde(default)]
+    pub validation_ignore: Vec<String>,
+    /// Left out of the generated data unless process-articles runs with `--include-drafts`
+    #[serde(default)]
+    pub draft: bool,
+    /// Left out of the generated data until this date. The site is static,
+    /// so the article appears with the first build after it.
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+impl Default for ArticleMetadata {
+    fn default() -> Self {
+        Self {
+            title: "Untitled".to_string(),
+            home_display: false,
+            category: None,
+            importance: default_importance(),
+            related_articles: Vec::new(),
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            author_image: None,
+            validation_ignore: Vec::new(),
+            draft: false,
+            publish_at: None,
+        }
+    }
+}
+
+impl ArticleMetadata {
+    /// Whether the article ships in a build made at `now`: not a draft and
+    /// not scheduled for later
+    pub fn is_published(&self, now: DateTime<Utc>) -> bool {
+        !self.draft && self.publish_at.is_none_or(|publish_at| publish_at <= now)
+    }
+}
+
+/// Parse a front matter date: RFC 3339 (`2024-01-15T09:30:00+09:00`), a
+/// date and time without offset (taken as UTC) or a date alone (midnight UTC)
+pub fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(date_time.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc())
+}
+
+fn deserialize_date<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+    match Option::<DateText>::deserialize(deserializer)? {
+        Some(DateText(value)) => parse_date(&value).map(Some).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid date '{}', expected YYYY-MM-DD or an RFC 3339 date and time",
+                value
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+/// A date as written: a string, or a TOML date value, which serde sees as
+/// a single-entry map holding the date's text
+struct DateText(String);
+
+impl<'de> Deserialize<'de> for DateText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateTextVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DateTextVisitor {
+            type Value = DateText;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a date")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<DateText, E> {
+                Ok(DateText(value.to_string()))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<DateText, A::Error> {
+                match map.next_entry::<String, String>()? {
+                    Some((_, value)) => Ok(DateText(value)),
+                    None => Err(serde::de::Error::invalid_length(0, &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(DateTextVisitor)
+    }
+}
+
+fn default_importance() -> u8 {
+    3
+}
+
+/// Types of links that can be extracted from markdown content
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LinkType {
+    WikiLink,      // [[article-name]], [[article-name#heading]] or [[article-name|label]] format
+    MarkdownLink,  // [text](slug) format
+}
+
+/// Represents a link found in markdown content
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExtractedLink {
+    pub target_slug: String,
+    pub link_type: LinkType,
+    pub context: String,
+    pub position: usize,
+    pub original_text: String,
+    /// Heading in the target article, as written after `#`
+    #[serde(default)]
+    pub section: Option<String>,
+    /// Display text: the `|label` of a wiki link or the text of a Markdown link
+    #[serde(default)]
+    pub label: Option<String>,
+    /// 1-based line in the source file, counting the front matter
+    #[serde(default)]
+    pub line: usize,
+    /// 1-based column in characters
+    #[serde(default)]
+    pub column: usize,
+}
+
+/// Represents an image referenced from markdown content
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssetReference {
+    pub path: String, // Image source as written, e.g. `images/diagram.png`
+    pub alt_text: String,
+    pub position: usize,
+    pub original_text: String,
+    /// 1-based line in the source file, counting the front matter
+    #[serde(default)]
+    pub line: usize,
+    /// 1-based column in characters
+    #[serde(default)]
+    pub column: usize,
+    /// Where the image was copied to, for local images found at build time
+    #[serde(default)]
+    pub published: Option<PublishedAsset>,
+}
+
+/// Content-hashed copy of a local image in the data output
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PublishedAsset {
+    pub url: String,
+    /// Pixel size of the original, when it could be read
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Downscaled copies, narrowest first
+    #[serde(default)]
+    pub variants: Vec<AssetVariant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssetVariant {
+    pub url: String,
+    pub width: u32,
+}
+
+impl PublishedAsset {
+    /// `srcset` attribute value listing the variants and the original,
+    /// or `None` when there are no variants to choose from
+    pub fn srcset(&self) -> Option<String> {
+        let width = self.width?;
+        if self.variants.is_empty() {
+            return None;
+        }
+        let candidates: Vec<String> = self
+            .variants
+            .iter()
+            .map(|variant| format!("{} {}w", variant.url, variant.width))
+            .chain(std::iter::once(format!("{} {}w", self.url, width)))
+            .collect();
+        Some(candidates.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExternalLink {
+    pub url: String,
+    pub position: usize,
+    /// 1-based line in the source file, counting the front matter
+    pub line: usize,
+    /// 1-based column in characters
+    pub column: usize,
+}
+
+/// Fully processed article as written to articles.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProcessedArticle {
+    pub slug: String,
+    pub title: String,
+    pub content: String,
+    pub html: String, // Sanitized HTML rendered from `content` at build time
+    pub metadata: ArticleMetadata,
+    /// False when the source had no front matter and `metadata` is the default
+    #[serde(default = "default_has_front_matter")]
+    pub has_front_matter: bool,
+    /// Where `metadata.created_at` and `metadata.updated_at` came from
+    #[serde(default)]
+    pub date_sources: DateSources,
+    pub file_path: String,
+    pub outbound_links: Vec<ExtractedLink>,
+    #[serde(default)]
+    pub assets: Vec<AssetReference>,
+    /// `http(s)://` links, only checked by `validate-links --check-external`
+    #[serde(default)]
+    pub external_links: Vec<ExternalLink>,
+    pub inbound_count: usize,
+    pub processed_at: String,
+}
+
+fn default_has_front_matter() -> bool {
+    true
+}
+
+/// Where an article date came from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DateSource {
+    FrontMatter,
+    /// First or last commit touching the file, see `process-articles --git-dates`
+    Git,
+}
+
+/// Sources of an article's dates; `None` while the date is unset
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DateSources {
+    pub created_at: Option<DateSource>,
+    pub updated_at: Option<DateSource>,
+}
+
+impl DateSources {
+    /// Dates set in front matter
+    pub fn from_metadata(metadata: &ArticleMetadata) -> Self {
+        Self {
+            created_at: metadata.created_at.map(|_| DateSource::FrontMatter),
+            updated_at: metadata.updated_at.map(|_| DateSource::FrontMatter),
+        }
+    }
+}
+
+/// Contents of articles.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticlesData {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub articles: Vec<ProcessedArticle>,
+    pub generated_at: String,
+    pub total_count: usize,
+    pub home_articles: Vec<String>,
+}
+
+/// Lightweight article data for list display (without full content)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LightweightArticle {
+    pub slug: String,
+    pub title: String,
+    pub summary: Option<String>, // First paragraph or excerpt
+    pub metadata: ArticleMetadata,
+    pub file_path: String,
+    pub outbound_targets: Vec<String>, // Distinct link targets, in order of appearance
+    pub outbound_count: usize,
+    pub inbound_count: usize,
+    pub processed_at: String,
+}
+
+impl From<&ProcessedArticle> for LightweightArticle {
+    fn from(article: &ProcessedArticle) -> Self {
+        let summary = extract_summary(&article.content);
+        let mut outbound_targets: Vec<String> = Vec::new();
+        for link in &article.outbound_links {
+            if !outbound_targets.contains(&link.target_slug) {
+                outbound_targets.push(link.target_slug.clone());
+            }
+        }
+
+        Self {
+            slug: article.slug.clone(),
+            title: article.title.clone(),
+            summary: (!summary.is_empty()).then_some(summary),
+            metadata: article.metadata.clone(),
+            file_path: article.file_path.clone(),
+            outbound_targets,
+            outbound_count: article.outbound_links.len(),
+            inbound_count: article.inbound_count,
+            processed_at: article.processed_at.clone(),
+        }
+    }
+}
+
+/// Extract a summary from article content (first paragraph, at most 200 characters)
+pub fn extract_summary(content: &str) -> String {
+    let mut summary_lines = Vec::new();
+    let mut found_content = false;
+    
+    for line in content.lines() {
+        let trimmed = line.trim();
+        
+        // Skip empty lines and headers at the beginning
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            if found_content {
+                break; // Stop at first empty line or header after content
+            }
+            continue;
+        }
+        
+        found_content = true;
+        summary_lines.push(trimmed);
+        
+        // Stop after first paragraph or when we have enough content
+        if summary_lines.join(" ").chars().count() > 200 {
+            break;
+        }
+    }
+    
+    let summary = summary_lines.join(" ");
+    if summary.chars().count() > 200 {
+        format!("{}...", summary.chars().take(197).collect::<String>())
+    } else {
+        summary
+    }
+}
+
+/// Contents of article-index.json: everything list and graph views need,
+/// without article bodies
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleIndex {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub articles: Vec<LightweightArticle>,
+    pub generated_at: String,
+    pub total_count: usize,
+    pub home_articles: Vec<String>,
+}
+
+/// Contents of a per-article file at [`article_content_path`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleContentData {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub article: ProcessedArticle,
+}
+
+/// Contents of link-graph.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LinkGraphData {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub graph: BTreeMap<String, GraphNode>,
+    pub generated_at: String,
+    pub total_connections: usize,
+    pub bidirectional_pairs: usize,
+    pub direct_links: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GraphNode {
+    pub connections: Vec<GraphConnection>,
+    pub inbound_count: usize,
+    pub outbound_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GraphConnection {
+    pub target: String,
+    pub connection_type: ConnectionType,
+    pub bidirectional: bool,
+    pub link_count: usize, // Number of actual links (for duplicate detection)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ConnectionType {
+    DirectLink,
+    Bidirectional,
+}
+
+/// Minimal view of a generated file used to check its version before
+/// deserializing the full payload. Files written before versioning was
+/// introduced have no `schema_version` and read as version 0.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchemaHeader {
+    #[serde(default)]
+    pub schema_version: u32,
+}
+
+/// Generated data was written with a schema this build cannot read
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaVersionMismatch {
+    pub found: u32,
+    pub expected: u32,
+}
+
+impl std::fmt::Display for SchemaVersionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "schema version {} is not supported (expected {}); regenerate the data with process-articles",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for SchemaVersionMismatch {}
+
+/// Check that data written with `found` can be read by this build
+pub fn check_schema_version(found: u32) -> Result<(), SchemaVersionMismatch> {
+    if found == SCHEMA_VERSION {
+        Ok(())
+    } else {
+        Err(SchemaVersionMismatch {
+            found,
+            expected: SCHEMA_VERSION,
+        })
+    }
+}
@@ -1,25 +1,33 @@
 use khimoo_portfolio::home::article_manager::ArticleManager;
-use khimoo_portfolio::home::data_loader::{ArticlesData, ProcessedArticle, ProcessedMetadata, LinkGraphData};
-use std::collections::HashMap;
+use khimoo_portfolio::home::data_loader::{ArticleMetadata, ArticlesData, LinkGraphData, ProcessedArticle, SCHEMA_VERSION};
+use std::collections::BTreeMap;
 
 fn create_test_article(slug: &str, title: &str, home_display: bool, importance: u8) -> ProcessedArticle {
     ProcessedArticle {
         slug: slug.to_string(),
         title: title.to_string(),
         content: format!("Content for {}", title),
-        metadata: ProcessedMetadata {
+        html: String::new(),
+        metadata: ArticleMetadata {
             title: title.to_string(),
             home_display,
             category: Some("test".to_string()),
-            importance: Some(importance),
+            importance,
             related_articles: Vec::new(),
             tags: vec!["test".to_string()],
             created_at: None,
             updated_at: None,
             author_image: None,
+            validation_ignore: vec![],
+            draft: false,
+            publish_at: None,
         },
+        has_front_matter: true,
+        date_sources: Default::default(),
         file_path: format!("articles/{}.md", slug),
         outbound_links: Vec::new(),
+        assets: Vec::new(),
+        external_links: Vec::new(),
         inbound_count: 0,
         processed_at: "2024-01-01T00:00:00Z".to_string(),
     }
@@ -37,6 +45,7 @@ fn test_load_from_data() {
     let mut manager = ArticleManager::new();
 
     let articles_data = ArticlesData {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("test1", "Test Article 1", true, 4),
             create_test_article("test2", "Test Article 2", false, 2),
@@ -47,11 +56,12 @@ fn test_load_from_data() {
     };
 
     let link_graph_data = LinkGraphData {
-        graph: HashMap::new(),
+        schema_version: SCHEMA_VERSION,
+        graph: BTreeMap::new(),
         generated_at: "2024-01-01T00:00:00Z".to_string(),
         total_connections: 0,
-        bidirectional_pairs: Some(0),
-        direct_links: Some(0),
+        bidirectional_pairs: 0,
+        direct_links: 0,
     };
 
     manager.load_from_data(articles_data, link_graph_data);
@@ -66,6 +76,7 @@ fn test_get_home_articles() {
     let mut manager = ArticleManager::new();
 
     let articles_data = ArticlesData {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("test1", "Test Article 1", true, 4),
             create_test_article("test2", "Test Article 2", false, 2),
@@ -76,11 +87,12 @@ fn test_get_home_articles() {
     };
 
     let link_graph_data = LinkGraphData {
-        graph: HashMap::new(),
+        schema_version: SCHEMA_VERSION,
+        graph: BTreeMap::new(),
         generated_at: "2024-01-01T00:00:00Z".to_string(),
         total_connections: 0,
-        bidirectional_pairs: Some(0),
-        direct_links: Some(0),
+        bidirectional_pairs: 0,
+        direct_links: 0,
     };
 
     manager.load_from_data(articles_data, link_graph_data);
@@ -1,22 +1,40 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
 
 use khimoo_portfolio::article_processing::{
     ArticleMetadata,
+    AssetVariant,
     ExtractedLink,
+    FrontMatterErrorKind,
     FrontMatterParser,
+    FRONT_MATTER_KEYS,
+    HtmlRenderer,
     LinkExtractor,
     LinkType,
+    PublishedAsset,
+    heading_anchors,
 };
 use khimoo_portfolio::article_processing::{
+    find_cycles,
     LinkValidator,
     ProcessedArticleRef,
+    ProjectConfig,
+    Severity,
     ValidationError,
     ValidationErrorType,
+    ValidationOptions,
     ValidationReport,
     ValidationReportFormatter,
     ValidationSummary,
+    ValidationWarningType,
 };
 
+fn date(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
 #[test]
 fn test_parse_complete_front_matter() {
     let content = r#"---
@@ -57,8 +75,8 @@ This is the markdown content.
     assert_eq!(metadata.importance, 4);
     assert_eq!(metadata.related_articles, vec!["article1", "article2"]);
     assert_eq!(metadata.tags, vec!["rust", "test"]);
-    assert_eq!(metadata.created_at, Some("2024-01-01T00:00:00Z".to_string()));
-    assert_eq!(metadata.updated_at, Some("2024-01-02T00:00:00Z".to_string()));
+    assert_eq!(metadata.created_at, Some(date("2024-01-01T00:00:00Z")));
+    assert_eq!(metadata.updated_at, Some(date("2024-01-02T00:00:00Z")));
     
     assert!(markdown.trim().starts_with("# Test Content"));
 }
@@ -137,9 +155,12 @@ fn test_validate_metadata_valid() {
         importance: 3,
         related_articles: vec!["article1".to_string()],
         tags: vec!["tag1".to_string()],
-        created_at: Some("2024-01-01T00:00:00Z".to_string()),
-        updated_at: Some("2024-01-02T00:00:00Z".to_string()),
+        created_at: Some(date("2024-01-01T00:00:00Z")),
+        updated_at: Some(date("2024-01-02T00:00:00Z")),
         author_image: None,
+        validation_ignore: vec![],
+        draft: false,
+        publish_at: None,
     };
 
     assert!(FrontMatterParser::validate_metadata(&metadata).is_ok());
@@ -171,16 +192,137 @@ fn test_validate_metadata_empty_title() {
 }
 
 #[test]
-fn test_validate_metadata_invalid_datetime() {
-    let metadata = ArticleMetadata {
-        title: "Test".to_string(),
-        created_at: Some("invalid-datetime".to_string()),
-        ..Default::default()
-    };
-
-    let result = FrontMatterParser::validate_metadata(&metadata);
+fn test_parse_invalid_datetime() {
+    let result = FrontMatterParser::parse("---\ntitle: Test\ncreated_at: invalid-datetime\n---\n");
     assert!(result.is_err());
-    assert!(result.unwrap_err().to_string().contains("Invalid created_at datetime format"));
+    assert!(format!("{:#}", result.unwrap_err()).contains("invalid date 'invalid-datetime'"));
+}
+
+#[test]
+fn test_front_matter_dates_accept_date_only() {
+    let content = "---\ntitle: Dates\ncreated_at: 2024-01-15\nupdated_at: \"2024-02-01T09:30:00+09:00\"\n---\n";
+    let (metadata, _) = FrontMatterParser::parse(content).unwrap();
+
+    assert_eq!(metadata.created_at, Some(date("2024-01-15T00:00:00Z")));
+    assert_eq!(metadata.updated_at, Some(date("2024-02-01T00:30:00Z")));
+    // Written back out as RFC 3339 in UTC
+    let json = serde_json::to_value(&metadata).unwrap();
+    assert_eq!(json["created_at"], "2024-01-15T00:00:00Z");
+}
+
+#[test]
+fn test_parse_strict_reports_problems_with_lines() {
+    let content = "---\ntitle: Strict\nhome_dispaly: true\nimportance: 9\ncreated_at: someday\nflavour: vanilla\n---\n\nBody\n";
+    let errors = FrontMatterParser::parse_strict(content).unwrap_err().0;
+
+    let summary: Vec<_> = errors.iter().map(|e| (e.kind.clone(), e.line)).collect();
+    assert_eq!(summary, vec![
+        (FrontMatterErrorKind::UnknownKey { key: "home_dispaly".to_string(), suggestion: Some("home_display".to_string()) }, Some(3)),
+        (FrontMatterErrorKind::UnknownKey { key: "flavour".to_string(), suggestion: None }, Some(6)),
+        (FrontMatterErrorKind::InvalidValue { key: Some("created_at".to_string()) }, Some(5)),
+    ]);
+    assert_eq!(errors[0].to_string(), "line 3, column 1: unknown key 'home_dispaly', did you mean 'home_display'?");
+
+    // Once the keys are fixed, out-of-range values are reported on their line
+    let content = "---\ntitle: Strict\nimportance: 9\n---\n";
+    let errors = FrontMatterParser::parse_strict(content).unwrap_err().0;
+    assert_eq!(errors[0].kind, FrontMatterErrorKind::InvalidValue { key: Some("importance".to_string()) });
+    assert_eq!(errors[0].line, Some(3));
+
+    let errors = FrontMatterParser::parse_strict("---\ntitle: Strict\ntags: [a, b\n---\n").unwrap_err().0;
+    assert_eq!(errors[0].kind, FrontMatterErrorKind::Syntax);
+    assert!(errors[0].line.is_some_and(|line| line >= 3));
+
+    // The lenient parser still accepts what it used to
+    assert!(FrontMatterParser::parse("---\ntitle: Lenient\nhome_dispaly: true\n---\n").is_ok());
+    assert!(FrontMatterParser::parse_strict("# No front matter\n").is_ok());
+    let (metadata, body, offset) = FrontMatterParser::parse_strict("---\ntitle: Fine\n---\nBody\n").unwrap();
+    assert_eq!((metadata.title.as_str(), body.as_str(), offset), ("Fine", "Body", 3));
+}
+
+#[test]
+fn test_parse_toml_front_matter() {
+    let content = r#"+++
+title = "TOML Article"
+home_display = true
+importance = 4
+tags = ["rust", "toml"]
+created_at = 2024-01-15
+updated_at = 2024-02-01T09:30:00+09:00
+publish_at = "2024-03-01"
++++
+
+Body with a --- rule
+"#;
+    let (metadata, markdown, offset) = FrontMatterParser::parse_with_body_offset(content).unwrap();
+
+    assert_eq!(metadata.title, "TOML Article");
+    assert!(metadata.home_display);
+    assert_eq!(metadata.importance, 4);
+    assert_eq!(metadata.tags, vec!["rust", "toml"]);
+    // Native TOML dates and quoted dates read the same way
+    assert_eq!(metadata.created_at, Some(date("2024-01-15T00:00:00Z")));
+    assert_eq!(metadata.updated_at, Some(date("2024-02-01T00:30:00Z")));
+    assert_eq!(metadata.publish_at, Some(date("2024-03-01T00:00:00Z")));
+    assert_eq!((markdown.as_str(), offset), ("\nBody with a --- rule", 9));
+
+    // Invalid TOML falls back to defaults like invalid YAML
+    let (metadata, markdown, offset) = FrontMatterParser::parse_with_body_offset("+++\ntitle = \n+++\nBody\n").unwrap();
+    assert_eq!((metadata.title.as_str(), markdown.as_str(), offset), ("Untitled", "+++\ntitle = \n+++\nBody\n", 0));
+}
+
+#[test]
+fn test_check_strict_toml_front_matter() {
+    let content = "+++\ntitle = \"Strict\"\nhome_dispaly = true\ncreated_at = \"someday\"\n+++\n\nBody\n";
+    let errors = FrontMatterParser::parse_strict(content).unwrap_err().0;
+
+    let summary: Vec<_> = errors.iter().map(|e| (e.kind.clone(), e.line)).collect();
+    assert_eq!(summary, vec![
+        (FrontMatterErrorKind::UnknownKey { key: "home_dispaly".to_string(), suggestion: Some("home_display".to_string()) }, Some(3)),
+        (FrontMatterErrorKind::InvalidValue { key: Some("created_at".to_string()) }, Some(4)),
+    ]);
+    assert_eq!(errors[1].column, Some(14));
+
+    let errors = FrontMatterParser::parse_strict("+++\ntitle = \"Strict\"\nimportance = 9\n+++\n").unwrap_err().0;
+    assert_eq!(errors[0].kind, FrontMatterErrorKind::InvalidValue { key: Some("importance".to_string()) });
+    assert_eq!(errors[0].line, Some(3));
+
+    let errors = FrontMatterParser::parse_strict("+++\ntitle = \"Strict\"\ntags = [\"a\"\n+++\n").unwrap_err().0;
+    assert_eq!(errors[0].kind, FrontMatterErrorKind::Syntax);
+    assert!(errors[0].line.is_some_and(|line| line >= 3));
+
+    let errors = FrontMatterParser::parse_strict("+++\ntitle = \"Open\"\n").unwrap_err().0;
+    assert_eq!(errors[0].to_string(), "line 1: front matter is not closed with a `+++` line");
+
+    let (metadata, body, offset) = FrontMatterParser::parse_strict("+++\ntitle = \"Fine\"\n+++\nBody\n").unwrap();
+    assert_eq!((metadata.title.as_str(), body.as_str(), offset), ("Fine", "Body", 3));
+}
+
+#[test]
+fn test_thematic_breaks_are_not_front_matter() {
+    for content in [
+        "# Notes\n---\nMiddle text\n---\n",
+        "# Notes\n+++\nMiddle text\n+++\n",
+        "# Notes\n\nAbove the break\n---\nBelow it\n",
+        "# Notes\n+++\n",
+    ] {
+        let (metadata, markdown, offset) = FrontMatterParser::parse_with_body_offset(content).unwrap();
+        assert_eq!((metadata, markdown.as_str(), offset), (ArticleMetadata::default(), content, 0), "{:?}", content);
+        assert!(FrontMatterParser::check_strict(content).is_ok(), "{:?}", content);
+    }
+
+    // A byte order mark before the opening delimiter is allowed
+    let (metadata, markdown, offset) = FrontMatterParser::parse_with_body_offset("\u{feff}---\ntitle: BOM\n---\nBody\n---\nMore\n").unwrap();
+    assert_eq!((metadata.title.as_str(), markdown.as_str(), offset), ("BOM", "Body\n---\nMore", 3));
+    assert!(FrontMatterParser::check_strict("\u{feff}+++\ntitle = \"BOM\"\n+++\n").is_ok());
+}
+
+#[test]
+fn test_front_matter_keys_match_metadata_fields() {
+    let json = serde_json::to_value(ArticleMetadata::default()).unwrap();
+    let fields: BTreeSet<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+    let keys: BTreeSet<&str> = FRONT_MATTER_KEYS.iter().copied().collect();
+    assert_eq!(fields, keys);
 }
 
 #[test]
@@ -220,13 +362,88 @@ fn test_extract_markdown_links() {
     assert_eq!(links[1].original_text, "[another one](second-slug)");
 }
 
+/// Markdown link destinations that aren't articles
+const NON_ARTICLE_LINKS: &[&str] = &[
+    "files/cv.pdf",
+    "tel:+81-3-1234-5678",
+    "ftp://x.org/a",
+    "/khimoo.io/about",
+    "//cdn.example.com/a",
+    "mailto:me@example.com",
+    "https://docs.rs",
+];
+
+#[test]
+fn test_markdown_links_are_classified_by_url_form() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content: String = NON_ARTICLE_LINKS
+        .iter()
+        .map(|target| format!("[file]({}) ", target))
+        .chain(["[HTTP](http-basics) [notes](tokio-basics.md#setup)".to_string()])
+        .collect();
+
+    let links = extractor.extract_links(&content);
+    let targets: Vec<_> = links.iter().map(|l| (l.target_slug.as_str(), l.section.as_deref())).collect();
+    assert_eq!(targets, vec![("http-basics", None), ("tokio-basics", Some("setup"))]);
+
+    let articles: Vec<ProcessedArticleRef> = ["notes", "http-basics", "tokio-basics"]
+        .into_iter()
+        .map(|slug| ProcessedArticleRef {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            metadata: ArticleMetadata { home_display: true, ..ArticleMetadata::default() },
+            outbound_links: if slug == "notes" { links.clone() } else { vec![] },
+            file_path: format!("{}.md", slug),
+            headings: vec!["setup".to_string()],
+            has_front_matter: true,
+        })
+        .collect();
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+    let broken: Vec<_> = report.errors.iter().map(|e| e.target_reference.as_str()).collect();
+    assert!(broken.is_empty(), "{:?}", broken);
+}
+
+#[test]
+fn test_extract_wiki_link_aliases_and_sections() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content = "Read [[rust-async|async Rust]], [[Rust Async#Error Handling]] and [[tokio#Runtime|the runtime]]. Also [setup](tokio#install).";
+    
+    let links = extractor.extract_links(content);
+    
+    assert_eq!(links.len(), 4);
+    
+    assert_eq!(links[0].target_slug, "rust-async");
+    assert_eq!(links[0].label.as_deref(), Some("async Rust"));
+    assert_eq!(links[0].section, None);
+    
+    assert_eq!(links[1].target_slug, "rust-async");
+    assert_eq!(links[1].section.as_deref(), Some("Error Handling"));
+    assert_eq!(links[1].label, None);
+    
+    assert_eq!(links[2].target_slug, "tokio");
+    assert_eq!(links[2].section.as_deref(), Some("Runtime"));
+    assert_eq!(links[2].label.as_deref(), Some("the runtime"));
+    
+    assert_eq!(links[3].target_slug, "tokio");
+    assert_eq!(links[3].link_type, LinkType::MarkdownLink);
+    assert_eq!(links[3].section.as_deref(), Some("install"));
+    assert_eq!(links[3].label.as_deref(), Some("setup"));
+}
+
+#[test]
+fn test_heading_anchors() {
+    let markdown = "# Intro\n\n## Error Handling\n\ntext\n\n## `Result` 型\n\n## Error Handling\n";
+    
+    assert_eq!(heading_anchors(markdown), vec!["intro", "error-handling", "result-型", "error-handling-1"]);
+}
+
 #[test]
 fn test_extract_mixed_links() {
     let extractor = LinkExtractor::new().unwrap();
     let content = r#"
-        Start with [[wiki link]] then [markdown link](slug-here).
-        Another [[Wiki Article]] and [external link](https://example.com).
-        "#;
+Start with [[wiki link]] then [markdown link](slug-here).
+Another [[Wiki Article]] and [external link](https://example.com).
+"#;
     
     let links = extractor.extract_links(content);
     
@@ -244,14 +461,71 @@ fn test_extract_mixed_links() {
     assert_eq!(links[2].target_slug, "wiki-article");
 }
 
+#[test]
+fn test_ignore_links_in_code() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content = r#"Real [[wiki link]] and [real](real-slug).
+
+Inline `[[not-a-link]]` and `[x](y)` code.
+
+```rust
+let v = vec![[a]]; // [[also-not-a-link]]
+let s = format!("[x](y)");
+```
+
+    [[indented-code]]
+"#;
+    
+    let links = extractor.extract_links(content);
+    
+    assert_eq!(links.len(), 2);
+    assert_eq!(links[0].target_slug, "wiki-link");
+    assert_eq!(links[0].original_text, "[[wiki link]]");
+    assert_eq!(&content[links[0].position..links[0].position + links[0].original_text.len()], "[[wiki link]]");
+    assert_eq!(links[1].target_slug, "real-slug");
+    assert_eq!(links[1].original_text, "[real](real-slug)");
+    assert_eq!(links[1].label.as_deref(), Some("real"));
+}
+
+#[test]
+fn test_link_line_and_column() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content = "# Title\n\nFirst [[alpha]] link.\n日本語の[[ベータ]]と[gamma](gamma).\n";
+    
+    let links = extractor.extract_links(content);
+    
+    assert_eq!(links.len(), 3);
+    assert_eq!((links[0].line, links[0].column), (3, 7));
+    assert_eq!((links[1].line, links[1].column), (4, 5));
+    assert_eq!((links[2].line, links[2].column), (4, 13));
+}
+
+#[test]
+fn test_extract_images_as_assets() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content = "Diagram: ![Task *graph*](images/graph.png) linked to [[tokio]].\n\n[![badge](badge.svg)](ci-status)\n";
+    
+    let references = extractor.extract(content);
+    
+    assert_eq!(references.links.len(), 2);
+    assert_eq!(references.links[0].target_slug, "tokio");
+    assert_eq!(references.links[1].target_slug, "ci-status");
+    
+    assert_eq!(references.assets.len(), 2);
+    assert_eq!(references.assets[0].path, "images/graph.png");
+    assert_eq!(references.assets[0].alt_text, "Task graph");
+    assert_eq!(references.assets[0].original_text, "![Task *graph*](images/graph.png)");
+    assert_eq!(references.assets[1].path, "badge.svg");
+}
+
 #[test]
 fn test_ignore_external_links() {
     let extractor = LinkExtractor::new().unwrap();
     let content = r#"
-        Internal: [article](internal-slug)
-        External: [website](https://example.com)
-        Email: [contact](mailto:test@example.com)
-        "#;
+Internal: [article](internal-slug)
+External: [website](https://example.com)
+Email: [contact](mailto:test@example.com)
+"#;
     
     let links = extractor.extract_links(content);
     
@@ -261,6 +535,19 @@ fn test_ignore_external_links() {
     assert_eq!(links[0].link_type, LinkType::MarkdownLink);
 }
 
+#[test]
+fn test_collect_external_links() {
+    let extractor = LinkExtractor::new().unwrap();
+    let content = "See [docs](https://docs.rs/tokio) and <http://example.com/a>.\n\n`[code](https://ignored.example)` [mail](mailto:a@example.com) [[internal]]\n";
+
+    let references = extractor.extract(content);
+
+    assert_eq!(references.links.len(), 1);
+    let urls: Vec<_> = references.external_links.iter().map(|link| link.url.as_str()).collect();
+    assert_eq!(urls, vec!["https://docs.rs/tokio", "http://example.com/a"]);
+    assert_eq!((references.external_links[1].line, references.external_links[1].column), (1, 39));
+}
+
 #[test]
 fn test_slug_generation_from_title() {
     let extractor = LinkExtractor::new().unwrap();
@@ -292,12 +579,12 @@ fn test_context_extraction() {
 fn test_context_with_multiline() {
     let extractor = LinkExtractor::new().unwrap();
     let content = r#"
-        This is the first line.
-        
-        This line contains a [[test link]] here.
-        
-        This is the last line.
-        "#;
+This is the first line.
+
+This line contains a [[test link]] here.
+
+This is the last line.
+"#;
     
     let links = extractor.extract_links(content);
     
@@ -315,11 +602,11 @@ fn test_real_article_patterns() {
     
     // Test pattern from rust-async.md
     let content = r#"
-        非同期プログラミングを理解するには、まず[[tokio-basics]]を理解することから始めましょう。
-        実用的な[パターン集](async-patterns)も参考になります。
-        
-        [[hello]]の記事でも触れましたが、非同期処理は重要です。
-        "#;
+非同期プログラミングを理解するには、まず[[tokio-basics]]を理解することから始めましょう。
+実用的な[パターン集](async-patterns)も参考になります。
+
+[[hello]]の記事でも触れましたが、非同期処理は重要です。
+"#;
     
     let links = extractor.extract_links(content);
     
@@ -342,9 +629,9 @@ fn test_broken_link_patterns() {
     
     // Test pattern from broken-link-test.md
     let content = r#"
-        - [[存在しない記事]]へのwikiリンク
-        - [壊れたリンク](broken-slug)へのmarkdownリンク
-        "#;
+- [[存在しない記事]]へのwikiリンク
+- [壊れたリンク](broken-slug)へのmarkdownリンク
+"#;
     
     let links = extractor.extract_links(content);
     
@@ -393,6 +680,10 @@ fn test_link_validator_broken_links() {
                     context: "Link to article2".to_string(),
                     position: 0,
                     original_text: "[[article2]]".to_string(),
+                    section: None,
+                    label: None,
+                    line: 0,
+                    column: 0,
                 },
                 ExtractedLink {
                     target_slug: "nonexistent".to_string(),
@@ -400,9 +691,15 @@ fn test_link_validator_broken_links() {
                     context: "Broken link".to_string(),
                     position: 20,
                     original_text: "[[nonexistent]]".to_string(),
+                    section: None,
+                    label: None,
+                    line: 0,
+                    column: 0,
                 },
             ],
             file_path: "article1.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
         ProcessedArticleRef {
             slug: "article2".to_string(),
@@ -410,6 +707,8 @@ fn test_link_validator_broken_links() {
             metadata: ArticleMetadata::default(),
             outbound_links: vec![],
             file_path: "article2.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
     ];
 
@@ -426,6 +725,52 @@ fn test_link_validator_broken_links() {
     assert_eq!(error.target_reference, "nonexistent");
 }
 
+#[test]
+fn test_link_validator_broken_section_links() {
+    let link = |section: &str| ExtractedLink {
+        target_slug: "target".to_string(),
+        link_type: LinkType::WikiLink,
+        context: "Section link".to_string(),
+        position: 0,
+        original_text: format!("[[target#{}]]", section),
+        section: Some(section.to_string()),
+        label: None,
+        line: 0,
+        column: 0,
+    };
+    let articles = vec![
+        ProcessedArticleRef {
+            slug: "source".to_string(),
+            title: "Source".to_string(),
+            metadata: ArticleMetadata::default(),
+            outbound_links: vec![link("Error Handling"), link("Eror Handling")],
+            file_path: "source.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
+        },
+        ProcessedArticleRef {
+            slug: "target".to_string(),
+            title: "Target".to_string(),
+            metadata: ArticleMetadata::default(),
+            outbound_links: vec![],
+            file_path: "target.md".to_string(),
+            headings: vec!["intro".to_string(), "error-handling".to_string()],
+            has_front_matter: true,
+        },
+    ];
+
+    let validator = LinkValidator::new(&articles);
+    let report = validator.validate_all().unwrap();
+
+    assert_eq!(report.summary.broken_links, 1);
+    assert_eq!(report.errors.len(), 1);
+    
+    let error = &report.errors[0];
+    assert_eq!(error.error_type, ValidationErrorType::BrokenSectionLink);
+    assert_eq!(error.target_reference, "target#Eror Handling");
+    assert_eq!(error.suggestion.as_deref(), Some("Did you mean 'target#error-handling'?"));
+}
+
 #[test]
 fn test_link_validator_invalid_related_articles() {
     let mut metadata = ArticleMetadata::default();
@@ -438,6 +783,8 @@ fn test_link_validator_invalid_related_articles() {
             metadata,
             outbound_links: vec![],
             file_path: "main.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
         ProcessedArticleRef {
             slug: "existing".to_string(),
@@ -445,6 +792,8 @@ fn test_link_validator_invalid_related_articles() {
             metadata: ArticleMetadata::default(),
             outbound_links: vec![],
             file_path: "existing.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
     ];
 
@@ -475,6 +824,8 @@ fn test_link_validator_warnings() {
             metadata: high_importance_metadata,
             outbound_links: vec![],
             file_path: "high.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
         ProcessedArticleRef {
             slug: "low-importance".to_string(),
@@ -487,9 +838,15 @@ fn test_link_validator_warnings() {
                     context: "Link to high importance".to_string(),
                     position: 0,
                     original_text: "[[high-importance]]".to_string(),
+                    section: None,
+                    label: None,
+                    line: 0,
+                    column: 0,
                 },
             ],
             file_path: "low.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
         ProcessedArticleRef {
             slug: "orphaned".to_string(),
@@ -497,6 +854,8 @@ fn test_link_validator_warnings() {
             metadata: ArticleMetadata::default(),
             outbound_links: vec![],
             file_path: "orphaned.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
     ];
 
@@ -511,6 +870,297 @@ fn test_link_validator_warnings() {
     assert!(warning_types.contains(&&khimoo_portfolio::article_processing::ValidationWarningType::MissingBacklinks));
 }
 
+#[test]
+fn test_find_cycles() {
+    let graph: BTreeMap<&str, BTreeSet<&str>> = [
+        ("a", ["b"].into_iter().collect()),
+        ("b", ["a", "c"].into_iter().collect()),
+        ("c", ["a", "d"].into_iter().collect()),
+        ("d", BTreeSet::new()),
+    ].into_iter().collect();
+
+    assert_eq!(find_cycles(&graph, 3), vec![vec!["a", "b", "c"]]);
+    assert_eq!(find_cycles(&graph, 2), vec![vec!["a", "b"], vec!["a", "b", "c"]]);
+    assert!(find_cycles(&graph, 4).is_empty());
+}
+
+#[test]
+fn test_find_cycles_is_bounded_on_dense_graphs() {
+    let nodes: Vec<String> = (0..15).map(|i| format!("n{:02}", i)).collect();
+    let graph: BTreeMap<&str, BTreeSet<&str>> = nodes
+        .iter()
+        .map(|node| (node.as_str(), nodes.iter().map(String::as_str).filter(|n| n != node).collect()))
+        .collect();
+
+    // With 16 no cycle is long enough, so only the step budget ends the search
+    for min_length in [3, 15, 16] {
+        let started = std::time::Instant::now();
+        let cycles = find_cycles(&graph, min_length);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5), "min_length {}", min_length);
+        assert!(cycles.len() <= 100);
+        assert!(cycles.iter().all(|cycle| cycle.len() >= min_length));
+    }
+    assert!(find_cycles(&graph, 16).is_empty());
+}
+
+#[test]
+fn test_link_validator_lists_issues_in_source_order() {
+    let article = |slug: String, links: &[(&str, usize)]| ProcessedArticleRef {
+        slug: slug.clone(),
+        title: slug.clone(),
+        metadata: ArticleMetadata::default(),
+        outbound_links: links.iter().map(|(target, line)| ExtractedLink {
+            target_slug: target.to_string(),
+            link_type: LinkType::WikiLink,
+            context: String::new(),
+            position: 0,
+            original_text: format!("[[{}]]", target),
+            section: None,
+            label: None,
+            line: *line,
+            column: 1,
+        }).collect(),
+        file_path: format!("articles/{}.md", slug),
+        headings: vec![],
+        has_front_matter: true,
+    };
+    let articles: Vec<_> = (0..20)
+        .map(|i| article(format!("a{:02}", i), &[("missing-b", 9), ("missing-a", 4)]))
+        .collect();
+
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+    let positions: Vec<_> = report.errors.iter()
+        .map(|e| (e.file_path.clone().unwrap(), e.line_number, e.error_type.rule_id()))
+        .collect();
+    let mut sorted = positions.clone();
+    sorted.sort();
+    assert_eq!(positions, sorted);
+    assert_eq!(positions[..2], [
+        ("articles/a00.md".to_string(), Some(4), "broken-link"),
+        ("articles/a00.md".to_string(), Some(9), "broken-link"),
+    ]);
+
+    // A second validator visits the articles in a different hash order
+    let again = LinkValidator::new(&articles).validate_all().unwrap();
+    assert_eq!(
+        serde_json::to_value(&report.errors).unwrap(),
+        serde_json::to_value(&again.errors).unwrap(),
+    );
+    assert_eq!(
+        serde_json::to_value(&report.warnings).unwrap(),
+        serde_json::to_value(&again.warnings).unwrap(),
+    );
+}
+
+#[test]
+fn test_link_validator_circular_references() {
+    let article = |slug: &str, related: &[&str], links: &[&str]| {
+        let mut metadata = ArticleMetadata::default();
+        metadata.related_articles = related.iter().map(|s| s.to_string()).collect();
+        ProcessedArticleRef {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            metadata,
+            outbound_links: links.iter().map(|target| ExtractedLink {
+                target_slug: target.to_string(),
+                link_type: LinkType::WikiLink,
+                context: String::new(),
+                position: 0,
+                original_text: format!("[[{}]]", target),
+                section: None,
+                label: None,
+                line: 0,
+                column: 0,
+            }).collect(),
+            file_path: format!("{}.md", slug),
+            headings: vec![],
+            has_front_matter: true,
+        }
+    };
+    let articles = vec![
+        article("x", &["y"], &["y"]),
+        article("y", &["z", "x"], &["x"]),
+        article("z", &["x"], &[]),
+    ];
+
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+    assert_eq!(report.summary.circular_references, 1);
+    let cycle = &report.errors[0];
+    assert_eq!(cycle.error_type, ValidationErrorType::CircularReference);
+    assert_eq!(cycle.source_article, "x");
+    assert_eq!(cycle.target_reference, "y → z → x");
+    assert!(cycle.context.as_ref().unwrap().contains("related_articles"));
+    // Cycles are warnings unless the config raises them
+    assert_eq!(cycle.severity, Severity::Warning);
+    assert!(report.article_stats["x"].has_warnings);
+    assert!(!report.article_stats["x"].has_errors);
+
+    let options = ValidationOptions {
+        rules: [("circular-reference".to_string(), Severity::Error)].into_iter().collect(),
+        ..Default::default()
+    };
+    let report = LinkValidator::with_options(&articles, options).validate_all().unwrap();
+    assert_eq!(report.errors[0].severity, Severity::Error);
+    assert!(report.article_stats["x"].has_errors);
+
+    let options = ValidationOptions { min_cycle_length: 2, ..Default::default() };
+    let report = LinkValidator::with_options(&articles, options).validate_all().unwrap();
+    // x ⇄ y in both graphs, plus x → y → z → x in related_articles
+    assert_eq!(report.summary.circular_references, 3);
+}
+
+#[test]
+fn test_link_validator_tag_and_category_checks() {
+    let article = |slug: &str, category: &str, tags: &[&str]| {
+        let mut metadata = ArticleMetadata::default();
+        metadata.category = Some(category.to_string());
+        metadata.tags = tags.iter().map(|s| s.to_string()).collect();
+        ProcessedArticleRef {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            metadata,
+            outbound_links: vec![],
+            file_path: format!("{}.md", slug),
+            headings: vec![],
+            has_front_matter: true,
+        }
+    };
+    let articles = vec![
+        article("a", "programming", &["rust", "async"]),
+        article("b", "programming", &["rust", "web"]),
+        article("c", "Programming", &["Rust", "web"]),
+    ];
+
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+    let warnings_of = |warning_type: ValidationWarningType| {
+        report.warnings.iter().filter(|w| w.warning_type == warning_type).collect::<Vec<_>>()
+    };
+
+    let unused = warnings_of(ValidationWarningType::UnusedTag);
+    assert_eq!(unused.len(), 1);
+    assert_eq!(unused[0].source_article, "a");
+    assert_eq!(unused[0].target_reference.as_deref(), Some("async"));
+
+    let casing = warnings_of(ValidationWarningType::InconsistentCasing);
+    assert_eq!(casing.len(), 2);
+    assert!(casing.iter().all(|w| w.source_article == "c"));
+    assert!(casing.iter().any(|w| w.target_reference.as_deref() == Some("Rust")
+        && w.suggestion.as_deref() == Some("Use 'rust' (used by 2 articles)")));
+    assert!(casing.iter().any(|w| w.target_reference.as_deref() == Some("Programming")));
+}
+
+#[test]
+fn test_link_validator_missing_metadata_and_orphans() {
+    let article = |slug: &str, links: &[&str]| ProcessedArticleRef {
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        metadata: ArticleMetadata::default(),
+        outbound_links: links.iter().map(|target| ExtractedLink {
+            target_slug: target.to_string(),
+            link_type: LinkType::WikiLink,
+            context: String::new(),
+            position: 0,
+            original_text: format!("[[{}]]", target),
+            section: None,
+            label: None,
+            line: 0,
+            column: 0,
+        }).collect(),
+        file_path: format!("{}.md", slug),
+        headings: vec![],
+        has_front_matter: true,
+    };
+    let mut home = article("home", &["linked"]);
+    home.metadata.home_display = true;
+    let mut no_front_matter = article("no-front-matter", &["home"]);
+    no_front_matter.has_front_matter = false;
+    let articles = vec![home, article("linked", &[]), no_front_matter];
+
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+
+    let missing: Vec<_> = report.errors.iter().filter(|e| e.error_type == ValidationErrorType::MissingMetadata).collect();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].source_article, "no-front-matter");
+
+    // Linking *to* a home article doesn't make an article reachable
+    let orphans: Vec<_> = report.errors.iter().filter(|e| e.error_type == ValidationErrorType::OrphanedArticle).collect();
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].source_article, "no-front-matter");
+    assert_eq!(report.summary.orphaned_articles, 1);
+}
+
+#[test]
+fn test_project_config_rules_and_suppression() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("khimoo.toml");
+    std::fs::write(&config_path, r#"
+[validation]
+high_importance = 5
+similarity_threshold = 0.9
+
+[validation.rules]
+broken-link = "warning"
+missing-backlinks = "off"
+"#).unwrap();
+    let options = ProjectConfig::load(&config_path).unwrap().validation;
+    assert_eq!(options.min_cycle_length, 3); // Unset values keep their defaults
+    assert_eq!(options.severity("broken-link", Severity::Error), Severity::Warning);
+
+    let article = |slug: &str, importance: u8, ignore: &[&str]| {
+        let mut metadata = ArticleMetadata::default();
+        metadata.importance = importance;
+        metadata.validation_ignore = ignore.iter().map(|s| s.to_string()).collect();
+        ProcessedArticleRef {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            metadata,
+            outbound_links: vec![ExtractedLink {
+                target_slug: "missng".to_string(),
+                link_type: LinkType::WikiLink,
+                context: String::new(),
+                position: 0,
+                original_text: "[[missng]]".to_string(),
+                section: None,
+                label: None,
+                line: 0,
+                column: 0,
+            }],
+            file_path: format!("{}.md", slug),
+            headings: vec![],
+            has_front_matter: true,
+        }
+    };
+    let articles = vec![
+        article("missing", 4, &[]),
+        article("quiet", 5, &["broken-link", "high-importance-with-few-links"]),
+    ];
+
+    let report = LinkValidator::with_options(&articles, options).validate_all().unwrap();
+
+    // Only the unsuppressed broken link remains, downgraded to a warning
+    // and without a suggestion below the raised similarity threshold
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].source_article, "missing");
+    assert_eq!(report.errors[0].severity, Severity::Warning);
+    assert_eq!(report.errors[0].suggestion, None);
+    assert_eq!(report.summary.total_errors, 0);
+    // importance 4 is below the configured high_importance of 5, and quiet suppresses it
+    assert!(report.warnings.is_empty());
+    assert_eq!(report.summary.total_warnings, 1);
+    assert!(!report.article_stats["missing"].has_errors);
+    assert!(report.article_stats["missing"].has_warnings);
+}
+
+#[test]
+fn test_project_config_rejects_unknown_rules() {
+    let config: ProjectConfig = toml::from_str("[validation.rules]\nbroken-links = \"off\"\n").unwrap();
+    let error = config.validation.check_rules().unwrap_err().to_string();
+    assert!(error.contains("Unknown validation rule 'broken-links'"));
+
+    assert!(toml::from_str::<ProjectConfig>("[validation.rules]\nbroken-link = \"fatal\"\n").is_err());
+    assert!(toml::from_str::<ProjectConfig>("[validation]\nmin_cycle = 2\n").is_err());
+}
+
 #[test]
 fn test_similarity_calculation() {
     let validator = LinkValidator::new(&[]);
@@ -536,6 +1186,8 @@ fn test_suggestion_generation() {
             metadata: ArticleMetadata::default(),
             outbound_links: vec![],
             file_path: "rust-async.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
         ProcessedArticleRef {
             slug: "tokio-basics".to_string(),
@@ -543,6 +1195,8 @@ fn test_suggestion_generation() {
             metadata: ArticleMetadata::default(),
             outbound_links: vec![],
             file_path: "tokio-basics.md".to_string(),
+            headings: vec![],
+            has_front_matter: true,
         },
     ];
 
@@ -558,6 +1212,48 @@ fn test_suggestion_generation() {
     assert!(suggestion.is_none());
 }
 
+#[test]
+fn test_suggestions_match_titles_and_tags_ranked() {
+    let article = |slug: &str, title: &str, tags: &[&str]| ProcessedArticleRef {
+        slug: slug.to_string(),
+        title: title.to_string(),
+        metadata: ArticleMetadata {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..ArticleMetadata::default()
+        },
+        outbound_links: vec![],
+        file_path: format!("{}.md", slug),
+        headings: vec![],
+        has_front_matter: true,
+    };
+    let articles = vec![
+        article("rust-async", "Rustでの非同期プログラミング", &["rust", "async"]),
+        article("async-patterns", "Async Patterns", &["async"]),
+        article("tokio-basics", "Tokio Basics", &["rust", "tokio"]),
+        article("profile", "自己紹介", &[]),
+    ];
+    let validator = LinkValidator::new(&articles);
+
+    // Reordered words still match the slug
+    let ranked = validator.similar_articles("async-rust");
+    assert_eq!(ranked[0], ("rust-async", 0.8));
+    assert_eq!(
+        validator.suggest_similar_article("rust-basics").as_deref(),
+        Some("Did you mean 'rust-async' or 'tokio-basics'?")
+    );
+
+    // Japanese references match against titles
+    assert_eq!(validator.best_similar_article("非同期プログラミング").unwrap().0, "rust-async");
+    assert_eq!(validator.suggest_similar_article("自己紹介ページ").as_deref(), Some("Did you mean 'profile'?"));
+
+    // A tag alone suggests an article but never counts as a confident match
+    assert_eq!(validator.suggest_similar_article("tokio").as_deref(), Some("Did you mean 'tokio-basics'?"));
+    assert!(validator.best_similar_article("tokio").unwrap().1 < 0.75);
+
+    let validator = LinkValidator::with_options(&articles, ValidationOptions { max_suggestions: 1, ..Default::default() });
+    assert_eq!(validator.suggest_similar_article("rust-basics").as_deref(), Some("Did you mean 'rust-async'?"));
+}
+
 #[test]
 fn test_validation_report_json_format() {
     let report = ValidationReport {
@@ -569,21 +1265,28 @@ fn test_validation_report_json_format() {
             invalid_references: 0,
             orphaned_articles: 0,
             circular_references: 0,
+            broken_external_links: 0,
+            missing_assets: 0,
             articles_with_errors: 1,
             articles_with_warnings: 0,
+            total_errors: 0,
+            total_warnings: 0,
         },
         errors: vec![
             ValidationError {
                 error_type: ValidationErrorType::BrokenLink,
+                severity: Severity::Error,
                 source_article: "test".to_string(),
                 target_reference: "missing".to_string(),
                 context: Some("test context".to_string()),
                 line_number: None,
+                column_number: None,
+                file_path: None,
                 suggestion: Some("Did you mean 'existing'?".to_string()),
             }
         ],
         warnings: vec![],
-        article_stats: HashMap::new(),
+        article_stats: BTreeMap::new(),
     };
 
     let json = ValidationReportFormatter::format_json(&report).unwrap();
@@ -607,21 +1310,28 @@ fn test_validation_report_console_format() {
             invalid_references: 0,
             orphaned_articles: 0,
             circular_references: 0,
+            broken_external_links: 0,
+            missing_assets: 0,
             articles_with_errors: 1,
             articles_with_warnings: 0,
+            total_errors: 0,
+            total_warnings: 0,
         },
         errors: vec![
             ValidationError {
                 error_type: ValidationErrorType::BrokenLink,
+                severity: Severity::Error,
                 source_article: "test".to_string(),
                 target_reference: "missing".to_string(),
                 context: Some("test context".to_string()),
                 line_number: None,
+                column_number: None,
+                file_path: None,
                 suggestion: Some("Did you mean 'existing'?".to_string()),
             }
         ],
         warnings: vec![],
-        article_stats: HashMap::new(),
+        article_stats: BTreeMap::new(),
     };
 
     let console = ValidationReportFormatter::format_console(&report);
@@ -632,6 +1342,100 @@ fn test_validation_report_console_format() {
     assert!(console.contains("💡 Did you mean 'existing'?"));
 }
 
+#[test]
+fn test_format_error_location() {
+    let mut error = ValidationError {
+        error_type: ValidationErrorType::BrokenLink,
+        severity: Severity::Error,
+        source_article: "test".to_string(),
+        target_reference: "missing".to_string(),
+        context: None,
+        line_number: Some(12),
+        column_number: Some(5),
+        file_path: Some("articles/test.md".to_string()),
+        suggestion: None,
+    };
+    assert_eq!(ValidationReportFormatter::format_error(&error), "articles/test.md:12:5 🔗 Broken Link: test → missing");
+
+    error.line_number = None;
+    error.column_number = None;
+    assert_eq!(ValidationReportFormatter::format_error(&error), "articles/test.md 🔗 Broken Link: test → missing");
+}
+
+#[test]
+fn test_validation_report_sarif_and_junit_formats() {
+    let article = |slug: &str, links: &[&str]| ProcessedArticleRef {
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        metadata: ArticleMetadata::default(),
+        outbound_links: links.iter().map(|target| ExtractedLink {
+            target_slug: target.to_string(),
+            link_type: LinkType::WikiLink,
+            context: String::new(),
+            position: 0,
+            original_text: format!("[[{}]]", target),
+            section: None,
+            label: None,
+            line: 3,
+            column: 7,
+        }).collect(),
+        file_path: format!("articles/{}.md", slug),
+        headings: vec![],
+        has_front_matter: true,
+    };
+    let articles = vec![article("a&b", &["missing"]), article("lonely", &[])];
+    let report = LinkValidator::new(&articles).validate_all().unwrap();
+
+    let sarif: serde_json::Value = serde_json::from_str(&ValidationReportFormatter::format_sarif(&report, Path::new(".")).unwrap()).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    let broken = results.iter().find(|r| r["ruleId"] == "broken-link").unwrap();
+    assert_eq!(broken["level"], "error");
+    let location = &broken["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "articles/a&b.md");
+    assert_eq!(location["region"]["startLine"], 3);
+    assert_eq!(location["region"]["startColumn"], 7);
+    let backlinks = results.iter().find(|r| r["ruleId"] == "missing-backlinks").unwrap();
+    assert_eq!(backlinks["level"], "warning");
+    assert_eq!(backlinks["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "articles/lonely.md");
+
+    let junit = ValidationReportFormatter::format_junit(&report);
+    assert!(junit.contains("<testsuites name=\"validate-links\" tests=\"2\" failures=\"1\">"));
+    assert!(junit.contains("<testcase name=\"a&amp;b\" classname=\"articles\">"));
+    assert!(junit.contains("<failure message=\"1 error(s)\">articles/a&amp;b.md:3:7 🔗 Broken Link: a&amp;b → missing"));
+    assert!(junit.contains("<system-out>🔗 Missing Backlinks: lonely"));
+}
+
+#[test]
+fn test_fix_article_source_related_articles_block_list() {
+    let article = |slug: &str, related: &[&str]| ProcessedArticleRef {
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        metadata: ArticleMetadata {
+            related_articles: related.iter().map(|s| s.to_string()).collect(),
+            ..ArticleMetadata::default()
+        },
+        outbound_links: vec![],
+        file_path: format!("{}.md", slug),
+        headings: vec![],
+        has_front_matter: true,
+    };
+    let articles = vec![
+        article("source", &["rust-asyncc", "pattern"]),
+        article("rust-async", &[]),
+        article("async-patterns", &[]),
+    ];
+    let validator = LinkValidator::new(&articles);
+    let content = "---\ntitle: Source\nrelated_articles:\n  - rust-asyncc   # typo\n  - pattern\ntags: [rust-asyncc]\n---\nBody\n";
+
+    let (fixed, fixes) = validator.fix_article_source("source", content);
+    assert_eq!(fixed, "---\ntitle: Source\nrelated_articles:\n  - rust-async   # typo\n  - pattern\ntags: [rust-asyncc]\n---\nBody\n");
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(fixes[0].line, 4);
+    assert_eq!(fixes[0].replacement, "rust-async");
+    assert!(fixes[0].confidence >= 0.75);
+}
+
 #[test]
 fn test_validation_report_ci_summary() {
     // Test successful validation
@@ -644,12 +1448,16 @@ fn test_validation_report_ci_summary() {
             invalid_references: 0,
             orphaned_articles: 0,
             circular_references: 0,
+            broken_external_links: 0,
+            missing_assets: 0,
             articles_with_errors: 0,
             articles_with_warnings: 1,
+            total_errors: 0,
+            total_warnings: 0,
         },
         errors: vec![],
         warnings: vec![],
-        article_stats: HashMap::new(),
+        article_stats: BTreeMap::new(),
     };
 
     let summary = ValidationReportFormatter::format_ci_summary(&success_report);
@@ -666,16 +1474,125 @@ fn test_validation_report_ci_summary() {
             invalid_references: 1,
             orphaned_articles: 0,
             circular_references: 0,
+            broken_external_links: 0,
+            missing_assets: 0,
             articles_with_errors: 1,
             articles_with_warnings: 0,
+            total_errors: 3,
+            total_warnings: 0,
         },
         errors: vec![],
         warnings: vec![],
-        article_stats: HashMap::new(),
+        article_stats: BTreeMap::new(),
     };
 
     let summary = ValidationReportFormatter::format_ci_summary(&failed_report);
     assert!(summary.contains("❌ Validation failed:"));
     assert!(summary.contains("2 broken links"));
     assert!(summary.contains("1 invalid references"));
-} 
\ No newline at end of file
+
+    // Errors without a counter of their own still fail the run
+    let mut orphaned_report = success_report.clone();
+    orphaned_report.summary.orphaned_articles = 1;
+    orphaned_report.summary.total_errors = 2;
+    let summary = ValidationReportFormatter::format_ci_summary(&orphaned_report);
+    assert!(summary.starts_with("❌ Validation failed: 2 errors (1 orphaned articles)"), "{}", summary);
+}
+
+#[test]
+fn test_html_renderer_renders_markdown() {
+    let renderer = HtmlRenderer::new();
+    let html = renderer.render("# Title\n\nSome *emphasis* and a [link](https://example.com).\n\n```rust\nfn main() {}\n```\n", &HashSet::new());
+
+    assert!(html.contains("<h1 id=\"title\">Title</h1>"));
+    assert!(html.contains("<em>emphasis</em>"));
+    assert!(html.contains("href=\"https://example.com\""));
+    assert!(html.contains("<code class=\"language-rust\">"));
+}
+
+#[test]
+fn test_html_renderer_sanitizes_raw_html() {
+    let renderer = HtmlRenderer::new();
+    let markdown = r#"Intro
+
+<script>alert("xss")</script>
+
+<img src="x.png" onerror="alert(1)">
+
+<a href="javascript:alert(1)">click</a> and <b onclick="alert(1)">bold</b>
+
+<iframe src="https://evil.example"></iframe>
+"#;
+    let html = renderer.render(markdown, &HashSet::new());
+
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("alert"));
+    assert!(!html.contains("onerror"));
+    assert!(!html.contains("javascript:"));
+    assert!(!html.contains("<iframe"));
+    // Allowed markup survives with its unsafe attributes removed
+    assert!(html.contains("<img src=\"x.png\">"));
+    assert!(html.contains("<b>bold</b>"));
+}
+
+#[test]
+fn test_html_renderer_rewrites_article_links() {
+    let renderer = HtmlRenderer::new();
+    let known_slugs: HashSet<&str> = ["rust-async", "tokio-basics"].into_iter().collect();
+    let markdown = "See [[Rust Async]], [Tokio](tokio-basics) and [[Missing Page]].\n\nExternal: [docs](https://docs.rs).\n\n`[[not-a-link]]`\n\n```\n[[also-not-a-link]]\n```\n";
+    let html = renderer.render(markdown, &known_slugs);
+
+    assert!(html.contains(r#"<a href="/article/rust-async" class="article-link" data-article-slug="rust-async" rel="noopener noreferrer">Rust Async</a>"#));
+    assert!(html.contains(r#"<a href="/article/tokio-basics" class="article-link" data-article-slug="tokio-basics" rel="noopener noreferrer">Tokio</a>"#));
+    assert!(html.contains(r#"class="article-link broken-link" data-article-slug="missing-page""#));
+    assert!(html.contains(r#"<a href="https://docs.rs" rel="noopener noreferrer">docs</a>"#));
+    assert!(html.contains("<code>[[not-a-link]]</code>"));
+    assert!(html.contains("[[also-not-a-link]]"));
+    assert!(!html.contains("[[Rust Async]]"));
+}
+
+#[test]
+fn test_html_renderer_leaves_non_article_links_alone() {
+    let renderer = HtmlRenderer::new();
+    let known_slugs: HashSet<&str> = ["http-basics", "tokio-basics"].into_iter().collect();
+    for target in NON_ARTICLE_LINKS {
+        let html = renderer.render(&format!("[file]({})", target), &known_slugs);
+        assert!(html.contains(&format!(r#"<a href="{}" rel="noopener noreferrer">file</a>"#, target)), "{}", html);
+        assert!(!html.contains("article-link"), "{}", html);
+    }
+
+    let html = renderer.render("[HTTP](http-basics) and [notes](tokio-basics.md#setup)", &known_slugs);
+    assert!(html.contains(r#"<a href="/article/http-basics" class="article-link" data-article-slug="http-basics" rel="noopener noreferrer">HTTP</a>"#));
+    assert!(html.contains(r#"<a href="/article/tokio-basics#setup" class="article-link" data-article-slug="tokio-basics" data-article-section="setup""#));
+}
+
+#[test]
+fn test_html_renderer_link_aliases_and_sections() {
+    let renderer = HtmlRenderer::new();
+    let known_slugs: HashSet<&str> = ["rust-async"].into_iter().collect();
+    let markdown = "## Error Handling\n\nSee [[rust-async|async Rust]], [[Rust Async#Error Handling]] and [details](rust-async#error-handling). [Up](#error-handling)\n";
+    let html = renderer.render(markdown, &known_slugs);
+
+    assert!(html.contains(r#"<h2 id="error-handling">Error Handling</h2>"#));
+    assert!(html.contains(r#"<a href="/article/rust-async" class="article-link" data-article-slug="rust-async" rel="noopener noreferrer">async Rust</a>"#));
+    assert!(html.contains(r#"<a href="/article/rust-async#error-handling" class="article-link" data-article-slug="rust-async" data-article-section="error-handling" rel="noopener noreferrer">Rust Async#Error Handling</a>"#));
+    assert!(html.contains(r#"data-article-section="error-handling" rel="noopener noreferrer">details</a>"#));
+    assert!(html.contains(r##"<a href="#error-handling" rel="noopener noreferrer">Up</a>"##));
+}
+
+#[test]
+fn test_html_renderer_points_images_at_published_assets() {
+    let renderer = HtmlRenderer::new();
+    let published = PublishedAsset {
+        url: "/data/assets/chart.0123456789ab.png".to_string(),
+        width: Some(1200),
+        height: Some(600),
+        variants: vec![AssetVariant { url: "/data/assets/chart.0123456789ab.w480.png".to_string(), width: 480 }],
+    };
+    let assets: HashMap<&str, &PublishedAsset> = [("img/chart.png", &published)].into_iter().collect();
+    let markdown = "![Sales *by* `region`](img/chart.png \"Q3\")\n\n![elsewhere](https://example.com/x.png)\n";
+    let html = renderer.render_with_assets(markdown, &HashSet::new(), &assets);
+
+    assert!(html.contains(r#"<img src="/data/assets/chart.0123456789ab.png" alt="Sales by region" title="Q3" srcset="/data/assets/chart.0123456789ab.w480.png 480w, /data/assets/chart.0123456789ab.png 1200w" width="1200" height="600" loading="lazy">"#));
+    assert!(html.contains(r#"<img src="https://example.com/x.png" alt="elsewhere">"#));
+}
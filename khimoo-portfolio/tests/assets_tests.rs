@@ -0,0 +1,114 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use khimoo_portfolio::article_processing::ArticlePipeline;
+use khimoo_portfolio::assets::{resolve_asset_path, AssetPublisher};
+use tempfile::TempDir;
+
+#[test]
+fn test_resolve_asset_path() {
+    let root = Path::new("articles");
+    let article = Path::new("articles/posts/intro.md");
+    assert_eq!(resolve_asset_path(root, article, "diagram.png"), Some(PathBuf::from("articles/posts/diagram.png")));
+    assert_eq!(resolve_asset_path(root, article, "../img/a.png?v=2#top"), Some(PathBuf::from("articles/posts/../img/a.png")));
+
+    for remote in ["https://example.com/a.png", "//cdn.example.com/a.png", "/static/a.png", "data:image/png;base64,AAAA", ""] {
+        assert_eq!(resolve_asset_path(root, article, remote), None, "{}", remote);
+    }
+    for outside in ["../../Cargo.lock", "../../../.ssh/id_rsa.png", "./../img/../../../x.png"] {
+        assert_eq!(resolve_asset_path(root, article, outside), None, "{}", outside);
+    }
+}
+
+#[test]
+fn test_publish_shares_copies_and_skips_unchanged_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let articles_dir = temp_dir.path().join("articles");
+    let output_dir = temp_dir.path().join("data");
+    fs::create_dir_all(articles_dir.join("nested")).unwrap();
+    image::RgbImage::new(640, 320).save(articles_dir.join("chart.png")).unwrap();
+    fs::write(articles_dir.join("logo.svg"), "<svg xmlns=\"http://www.w3.org/2000/svg\"/>").unwrap();
+
+    fs::write(articles_dir.join("a.md"), "---\ntitle: A\n---\n![chart](chart.png) ![logo](logo.svg) ![gone](gone.png)\n").unwrap();
+    fs::write(articles_dir.join("nested/b.md"), "---\ntitle: B\n---\n![same chart](../chart.png)\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(&articles_dir).unwrap();
+    let publisher = AssetPublisher::new(&articles_dir, &output_dir).with_variant_widths(vec![960, 480]);
+
+    let mut articles = pipeline.load_articles().unwrap();
+    let summary = publisher.publish_articles(&mut articles).unwrap();
+    assert_eq!((summary.images, summary.written, summary.missing, summary.removed), (2, 3, 1, 0));
+    assert_eq!(summary.outside_articles, 0);
+
+    let chart = articles[0].assets[0].published.clone().unwrap();
+    assert_eq!(articles[1].assets[0].published.as_ref(), Some(&chart));
+    // Only widths below the original are written
+    assert_eq!(chart.variants.iter().map(|v| v.width).collect::<Vec<_>>(), vec![480]);
+    assert_eq!(chart.srcset().unwrap(), format!("{} 480w, {} 640w", chart.variants[0].url, chart.url));
+
+    let logo = articles[0].assets[1].published.clone().unwrap();
+    assert!(logo.url.ends_with(".svg"));
+    assert_eq!((logo.width, logo.variants.len()), (None, 0));
+    assert!(articles[0].assets[2].published.is_none());
+
+    // A second run finds every file in place
+    let mut articles = pipeline.load_articles().unwrap();
+    let summary = publisher.publish_articles(&mut articles).unwrap();
+    assert_eq!((summary.images, summary.written, summary.removed), (2, 0, 0));
+}
+
+#[test]
+fn test_files_outside_the_articles_directory_are_not_published() {
+    let temp_dir = TempDir::new().unwrap();
+    let articles_dir = temp_dir.path().join("articles");
+    let output_dir = temp_dir.path().join("data");
+    fs::create_dir_all(&articles_dir).unwrap();
+    fs::write(temp_dir.path().join("secret.png"), b"private key").unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(temp_dir.path().join("secret.png"), articles_dir.join("linked.png")).unwrap();
+
+    fs::write(
+        articles_dir.join("a.md"),
+        "---\ntitle: A\nauthor_image: ../secret.png\n---\n![up](../secret.png) ![linked](linked.png)\n",
+    ).unwrap();
+
+    let pipeline = ArticlePipeline::new(&articles_dir).unwrap();
+    let publisher = AssetPublisher::new(&articles_dir, &output_dir);
+
+    let mut articles = pipeline.load_articles().unwrap();
+    let summary = publisher.publish_articles(&mut articles).unwrap();
+    let symlinks = if cfg!(unix) { 1 } else { 0 };
+    assert_eq!((summary.images, summary.written, summary.outside_articles), (0, 0, 2 + symlinks));
+    assert!(articles[0].assets.iter().all(|asset| asset.published.is_none()));
+    assert_eq!(articles[0].metadata.author_image.as_deref(), Some("../secret.png"));
+    assert_eq!(fs::read_dir(publisher.asset_dir()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_unreadable_images_are_published_as_is() {
+    let temp_dir = TempDir::new().unwrap();
+    let articles_dir = temp_dir.path().join("articles");
+    let output_dir = temp_dir.path().join("data");
+    fs::create_dir_all(&articles_dir).unwrap();
+    image::RgbImage::new(640, 320).save(articles_dir.join("whole.png")).unwrap();
+    let png = fs::read(articles_dir.join("whole.png")).unwrap();
+    // A valid header with the image data cut off, and a JPEG named .png
+    fs::write(articles_dir.join("truncated.png"), &png[..png.len() / 2]).unwrap();
+    fs::write(articles_dir.join("corrupt.png"), b"\xff\xd8\xff\xe0 not a png").unwrap();
+
+    fs::write(articles_dir.join("a.md"), "---\ntitle: A\n---\n![t](truncated.png) ![c](corrupt.png)\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(&articles_dir).unwrap();
+    let publisher = AssetPublisher::new(&articles_dir, &output_dir).with_variant_widths(vec![480]);
+
+    let mut articles = pipeline.load_articles().unwrap();
+    let summary = publisher.publish_articles(&mut articles).unwrap();
+    assert_eq!((summary.images, summary.written), (2, 2));
+    for asset in &articles[0].assets {
+        let published = asset.published.as_ref().unwrap();
+        assert!(published.url.ends_with(".png"), "{}", published.url);
+        assert_eq!((published.width, published.height, published.variants.len()), (None, None, 0));
+    }
+}
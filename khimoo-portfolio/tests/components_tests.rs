@@ -1,33 +1,42 @@
 use khimoo_portfolio::home::components::{create_node_registry_from_articles, find_author_article};
 use khimoo_portfolio::home::components::{ContainerBound, NodeContent, NodeId};
-use khimoo_portfolio::home::data_loader::{ArticlesData, ProcessedArticle, ProcessedMetadata};
+use khimoo_portfolio::home::data_loader::{ArticleIndex, ArticleMetadata, LightweightArticle, ProcessedArticle, SCHEMA_VERSION};
 
-fn create_test_article(slug: &str, title: &str, author_image: Option<String>) -> ProcessedArticle {
-    ProcessedArticle {
+fn create_test_article(slug: &str, title: &str, author_image: Option<String>) -> LightweightArticle {
+    LightweightArticle::from(&ProcessedArticle {
         slug: slug.to_string(),
         title: title.to_string(),
         content: "Test content".to_string(),
-        metadata: ProcessedMetadata {
+        html: String::new(),
+        metadata: ArticleMetadata {
             title: title.to_string(),
             home_display: true,
             category: None,
-            importance: Some(3),
+            importance: 3,
             related_articles: vec![],
             tags: vec![],
             created_at: None,
             updated_at: None,
             author_image,
+            validation_ignore: vec![],
+            draft: false,
+            publish_at: None,
         },
+        has_front_matter: true,
+        date_sources: Default::default(),
         file_path: format!("{}.md", slug),
         outbound_links: vec![],
+        assets: vec![],
+        external_links: vec![],
         inbound_count: 0,
         processed_at: "2024-01-01T00:00:00Z".to_string(),
-    }
+    })
 }
 
 #[test]
 fn test_find_author_article_none_found() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("article1", "Article 1", None),
             create_test_article("article2", "Article 2", None),
@@ -43,7 +52,8 @@ fn test_find_author_article_none_found() {
 
 #[test]
 fn test_find_author_article_single_found() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("article1", "Article 1", None),
             create_test_article("author", "About Me", Some("/images/profile.jpg".to_string())),
@@ -64,7 +74,8 @@ fn test_find_author_article_single_found() {
 
 #[test]
 fn test_find_author_article_multiple_found() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("author1", "About Me 1", Some("/images/profile1.jpg".to_string())),
             create_test_article("article1", "Article 1", None),
@@ -85,7 +96,8 @@ fn test_find_author_article_multiple_found() {
 
 #[test]
 fn test_find_author_article_empty_articles() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![],
         generated_at: "2024-01-01T00:00:00Z".to_string(),
         total_count: 0,
@@ -98,7 +110,8 @@ fn test_find_author_article_empty_articles() {
 
 #[test]
 fn test_create_node_registry_with_author_metadata() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("author", "About Khimoo", Some("/images/profile.jpg".to_string())),
             create_test_article("article1", "Article 1", None),
@@ -134,7 +147,8 @@ fn test_create_node_registry_with_author_metadata() {
 
 #[test]
 fn test_create_node_registry_fallback_without_author_metadata() {
-    let articles_data = ArticlesData {
+    let articles_data = ArticleIndex {
+        schema_version: SCHEMA_VERSION,
         articles: vec![
             create_test_article("article1", "Article 1", None),
             create_test_article("article2", "Article 2", None),
@@ -0,0 +1,170 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use khimoo_portfolio::external_links::{
+    ExternalLinkCache, ExternalLinkChecker, ExternalLinkOptions, ExternalLinkStatus, HttpClient, MAX_RETRY_DELAY,
+};
+use tempfile::TempDir;
+
+/// Answers from a fixed script per URL; the last answer repeats
+struct FakeClient {
+    responses: HashMap<&'static str, Vec<Option<u16>>>,
+    calls: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl FakeClient {
+    fn new(responses: &[(&'static str, &[Option<u16>])]) -> (Self, Arc<Mutex<HashMap<String, usize>>>) {
+        let calls = Arc::new(Mutex::new(HashMap::new()));
+        let client = Self {
+            responses: responses.iter().map(|(url, answers)| (*url, answers.to_vec())).collect(),
+            calls: calls.clone(),
+        };
+        (client, calls)
+    }
+}
+
+impl HttpClient for FakeClient {
+    fn status(&self, url: &str) -> Result<u16> {
+        let mut calls = self.calls.lock().unwrap();
+        let count = calls.entry(url.to_string()).or_insert(0);
+        let answers = &self.responses[url];
+        let answer = answers[(*count).min(answers.len() - 1)];
+        *count += 1;
+        answer.ok_or_else(|| anyhow::anyhow!("connection refused"))
+    }
+}
+
+fn options() -> ExternalLinkOptions {
+    ExternalLinkOptions { retry_delay_ms: 0, ..Default::default() }
+}
+
+#[test]
+fn test_transient_failures_are_retried() {
+    let (client, calls) = FakeClient::new(&[
+        ("https://flaky.example", &[Some(503), None, Some(200)]),
+        ("https://gone.example", &[Some(404)]),
+        ("https://down.example", &[None]),
+    ]);
+    let checker = ExternalLinkChecker::new(Box::new(client), options());
+
+    let results = checker.check_urls(["https://flaky.example", "https://gone.example", "https://down.example"]).unwrap();
+    assert_eq!(results["https://flaky.example"], ExternalLinkStatus::Ok { status: 200 });
+    assert_eq!(results["https://gone.example"], ExternalLinkStatus::HttpError { status: 404 });
+    assert!(matches!(results["https://down.example"], ExternalLinkStatus::Unreachable { .. }));
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls["https://flaky.example"], 3);
+    assert_eq!(calls["https://gone.example"], 1);
+    assert_eq!(calls["https://down.example"], 3);
+}
+
+#[test]
+fn test_retry_delay_doubles_up_to_the_cap() {
+    let options = ExternalLinkOptions { retry_delay_ms: 500, ..Default::default() };
+    let delays: Vec<u64> = (0..4).map(|attempt| options.retry_delay(attempt).as_millis() as u64).collect();
+    assert_eq!(delays, vec![500, 1000, 2000, 4000]);
+    for attempt in [6, 63, 64, 200, u32::MAX] {
+        assert_eq!(options.retry_delay(attempt), MAX_RETRY_DELAY, "attempt {}", attempt);
+    }
+
+    let huge = ExternalLinkOptions { retry_delay_ms: u64::MAX, ..Default::default() };
+    assert_eq!(huge.retry_delay(1), MAX_RETRY_DELAY);
+}
+
+#[test]
+fn test_results_are_cached_until_ttl() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache_file = temp_dir.path().join("external.json");
+    let urls = ["https://ok.example", "https://down.example"];
+
+    let (client, calls) = FakeClient::new(&[("https://ok.example", &[Some(200)]), ("https://down.example", &[None])]);
+    let checker = ExternalLinkChecker::new(Box::new(client), options()).with_cache_file(cache_file.clone());
+    checker.check_urls(urls).unwrap();
+
+    // Definitive results are reused; unreachable hosts are checked again
+    let (client, second_calls) = FakeClient::new(&[("https://ok.example", &[Some(500)]), ("https://down.example", &[Some(200)])]);
+    let checker = ExternalLinkChecker::new(Box::new(client), options()).with_cache_file(cache_file.clone());
+    let results = checker.check_urls(urls).unwrap();
+    assert_eq!(results["https://ok.example"], ExternalLinkStatus::Ok { status: 200 });
+    assert_eq!(results["https://down.example"], ExternalLinkStatus::Ok { status: 200 });
+    assert_eq!(second_calls.lock().unwrap().get("https://ok.example"), None);
+    assert_eq!(calls.lock().unwrap()["https://ok.example"], 1);
+
+    let cache = ExternalLinkCache::load(&cache_file).unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(cache.fresh("https://ok.example", Duration::hours(24), Utc::now()).is_some());
+    assert!(cache.fresh("https://ok.example", Duration::hours(24), Utc::now() + Duration::hours(25)).is_none());
+}
+
+#[test]
+fn test_concurrency_is_limited() {
+    struct SlowClient {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl HttpClient for SlowClient {
+        fn status(&self, _url: &str) -> Result<u16> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(200)
+        }
+    }
+
+    let client = Arc::new(SlowClient { in_flight: AtomicUsize::new(0), max_in_flight: AtomicUsize::new(0) });
+    struct Shared(Arc<SlowClient>);
+    impl HttpClient for Shared {
+        fn status(&self, url: &str) -> Result<u16> {
+            self.0.status(url)
+        }
+    }
+
+    let urls: Vec<String> = (0..12).map(|i| format!("https://example.com/{}", i)).collect();
+    let checker = ExternalLinkChecker::new(Box::new(Shared(client.clone())), ExternalLinkOptions { concurrency: 3, ..options() });
+    let results = checker.check_urls(urls.iter().map(String::as_str)).unwrap();
+
+    assert_eq!(results.len(), 12);
+    assert!(client.max_in_flight.load(Ordering::SeqCst) <= 3);
+}
+
+#[cfg(feature = "cli-tools")]
+#[test]
+fn test_ureq_client_against_local_server() {
+    use khimoo_portfolio::external_links::UreqClient;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            // Drain the headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap_or(0) > 2 {
+                line.clear();
+            }
+            let status = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+                [_, "/ok", ..] => "200 OK",
+                ["HEAD", "/get-only", ..] => "405 Method Not Allowed",
+                ["GET", "/get-only", ..] => "200 OK",
+                _ => "404 Not Found",
+            };
+            let _ = write!(reader.get_mut(), "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status);
+        }
+    });
+
+    let client = UreqClient::new(std::time::Duration::from_secs(5));
+    assert_eq!(client.status(&format!("{}/ok", base)).unwrap(), 200);
+    assert_eq!(client.status(&format!("{}/missing", base)).unwrap(), 404);
+    assert_eq!(client.status(&format!("{}/get-only", base)).unwrap(), 200);
+}
@@ -0,0 +1,68 @@
+#![cfg(all(not(target_arch = "wasm32"), feature = "cli-tools"))]
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use git2::{Repository, Signature, Time};
+use khimoo_portfolio::article_processing::{ArticlePipeline, DateSource, DateSources};
+use khimoo_portfolio::git_dates::GitDates;
+use tempfile::TempDir;
+
+/// Commit the current state of `paths` with the given author time
+fn commit(repo: &Repository, paths: &[&str], seconds: i64) {
+    let mut index = repo.index().unwrap();
+    for path in paths {
+        index.add_path(Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::new("Author", "author@example.com", &Time::new(seconds, 0)).unwrap();
+    let parent = repo.head().ok().map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<_> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &signature, &signature, "update", &tree, &parents).unwrap();
+}
+
+fn time(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, 0).unwrap()
+}
+
+#[test]
+fn test_dates_come_from_first_and_last_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = Repository::init(temp_dir.path()).unwrap();
+    let articles_dir = temp_dir.path().join("articles");
+    fs::create_dir_all(&articles_dir).unwrap();
+
+    fs::write(articles_dir.join("plain.md"), "---\ntitle: Plain\n---\nv1\n").unwrap();
+    fs::write(articles_dir.join("dated.md"), "---\ntitle: Dated\ncreated_at: 2020-05-01\n---\nv1\n").unwrap();
+    fs::write(temp_dir.path().join("README.md"), "outside the articles\n").unwrap();
+    commit(&repo, &["articles/plain.md", "articles/dated.md", "README.md"], 1_700_000_000);
+
+    fs::write(articles_dir.join("plain.md"), "---\ntitle: Plain\n---\nv2\n").unwrap();
+    fs::write(articles_dir.join("dated.md"), "---\ntitle: Dated\ncreated_at: 2020-05-01\n---\nv2\n").unwrap();
+    commit(&repo, &["articles/plain.md", "articles/dated.md"], 1_700_086_400);
+
+    fs::write(articles_dir.join("draft.md"), "---\ntitle: Uncommitted\n---\n").unwrap();
+
+    let git_dates = GitDates::open(&articles_dir).unwrap();
+    assert!(!git_dates.is_shallow());
+    assert_eq!(git_dates.dates(&temp_dir.path().join("README.md")), None);
+
+    let mut articles = ArticlePipeline::new(&articles_dir).unwrap().load_articles().unwrap();
+    assert_eq!(git_dates.fill_missing_dates(&mut articles), 2);
+
+    let [dated, draft, plain] = &articles[..] else { panic!("expected three articles") };
+
+    // Front matter wins over git; only the missing date is filled
+    assert_eq!(dated.metadata.created_at, Some("2020-05-01T00:00:00Z".parse().unwrap()));
+    assert_eq!(dated.metadata.updated_at, Some(time(1_700_086_400)));
+    assert_eq!(dated.date_sources, DateSources { created_at: Some(DateSource::FrontMatter), updated_at: Some(DateSource::Git) });
+
+    assert_eq!(plain.metadata.created_at, Some(time(1_700_000_000)));
+    assert_eq!(plain.metadata.updated_at, Some(time(1_700_086_400)));
+    assert_eq!(plain.date_sources, DateSources { created_at: Some(DateSource::Git), updated_at: Some(DateSource::Git) });
+
+    assert_eq!(draft.metadata.created_at, None);
+    assert_eq!(draft.date_sources, DateSources::default());
+}
@@ -1,7 +1,12 @@
 #![cfg(not(target_arch = "wasm32"))]
 
 use khimoo_portfolio::FrontMatterParser;
-use khimoo_portfolio::home::data_loader::{ArticlesData, ProcessedArticle, ProcessedMetadata};
+use khimoo_portfolio::article_processing::{generate_slug, ArticlePipeline, ConnectionType, ValidationReportFormatter};
+use khimoo_portfolio::home::data_loader::{ArticleMetadata, ArticlesData, LightweightArticle, LinkGraphData, ProcessedArticle, SCHEMA_VERSION};
+use khimoo_portfolio::schema::{check_schema_version, SchemaHeader};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
 
 #[test]
 fn test_front_matter_tag_integration() {
@@ -71,24 +76,33 @@ fn test_node_navigation_integration() {
         slug: "test-article".to_string(),
         title: "Test Article".to_string(),
         content: "# Test Article\n\nThis is a test article.".to_string(),
-        metadata: ProcessedMetadata {
+        html: String::new(),
+        metadata: ArticleMetadata {
             title: "Test Article".to_string(),
             home_display: true,
             category: Some("test".to_string()),
-            importance: Some(3),
+            importance: 3,
             related_articles: vec![],
             tags: vec!["test".to_string()],
             created_at: None,
             updated_at: None,
             author_image: None,
+            validation_ignore: vec![],
+            draft: false,
+            publish_at: None,
         },
+        has_front_matter: true,
+        date_sources: Default::default(),
         file_path: "articles/test-article.md".to_string(),
         outbound_links: vec![],
+        assets: vec![],
+        external_links: vec![],
         inbound_count: 0,
         processed_at: "2024-01-01T00:00:00Z".to_string(),
     };
 
     let articles_data = ArticlesData {
+        schema_version: SCHEMA_VERSION,
         articles: vec![test_article],
         generated_at: "2024-01-01T00:00:00Z".to_string(),
         total_count: 1,
@@ -99,4 +113,161 @@ fn test_node_navigation_integration() {
     assert_eq!(articles_data.articles[0].slug, "test-article");
     assert_eq!(articles_data.articles[0].metadata.home_display, true);
     assert!(articles_data.home_articles.contains(&"test-article".to_string()));
-} 
\ No newline at end of file
+}
+
+#[test]
+fn test_generate_slug_matches_wiki_link_slugs() {
+    assert_eq!(generate_slug(Path::new("articles/rust-async.md")), "rust-async");
+    assert_eq!(generate_slug(Path::new("articles/Tokio Basics.md")), "tokio-basics");
+    assert_eq!(generate_slug(Path::new("articles/存在しない記事.md")), "存在しない記事");
+}
+
+#[test]
+fn test_article_pipeline_end_to_end() {
+    let temp_dir = TempDir::new().unwrap();
+    let articles_dir = temp_dir.path();
+    fs::create_dir_all(articles_dir.join("nested")).unwrap();
+
+    fs::write(
+        articles_dir.join("alpha.md"),
+        "---\ntitle: \"Alpha\"\nhome_display: true\n---\n\nSee [[beta]], [[Beta]] and [[missing]].\n",
+    ).unwrap();
+    fs::write(
+        articles_dir.join("nested").join("Beta.md"),
+        "---\ntitle: \"Beta\"\n---\n\nBack to [Alpha](alpha).\n",
+    ).unwrap();
+
+    let pipeline = ArticlePipeline::new(articles_dir).unwrap();
+    let mut articles = pipeline.load_articles().unwrap();
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].slug, "alpha");
+    assert_eq!(articles[1].slug, "beta");
+
+    ArticlePipeline::calculate_inbound_counts(&mut articles);
+    assert_eq!(articles[0].inbound_count, 1);
+    assert_eq!(articles[1].inbound_count, 2);
+
+    let link_graph = ArticlePipeline::build_link_graph(&articles);
+    let alpha = &link_graph.graph["alpha"];
+    assert_eq!(alpha.connections.len(), 1);
+    assert_eq!(alpha.connections[0].target, "beta");
+    assert_eq!(alpha.connections[0].link_count, 2);
+    assert_eq!(alpha.connections[0].connection_type, ConnectionType::Bidirectional);
+    assert_eq!(link_graph.bidirectional_pairs, 1);
+    assert_eq!(link_graph.direct_links, 0);
+
+    let articles_data = ArticlePipeline::build_articles_data(&articles);
+    assert_eq!(articles_data.home_articles, vec!["alpha".to_string()]);
+
+    let report = pipeline.validate(&articles).unwrap();
+    assert_eq!(report.summary.broken_links, 1);
+    assert_eq!(report.errors[0].target_reference, "missing");
+}
+
+#[test]
+fn test_validation_errors_point_into_source_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("a.md");
+    fs::write(&path, "---\ntitle: \"A\"\ntags: [\"x\"]\n---\n\nIntro line.\nSee [[missing]] here.\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(temp_dir.path()).unwrap();
+    let articles = pipeline.load_articles().unwrap();
+    let link = &articles[0].outbound_links[0];
+    assert_eq!((link.line, link.column), (7, 5));
+
+    let report = pipeline.validate(&articles).unwrap();
+    let formatted = ValidationReportFormatter::format_error(&report.errors[0]);
+    assert!(formatted.starts_with(&format!("{}:7:5 ", path.display())));
+}
+
+#[test]
+fn test_article_without_front_matter_is_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("home.md"), "---\ntitle: \"Home\"\nhome_display: true\n---\n\n[[plain]]\n").unwrap();
+    fs::write(temp_dir.path().join("plain.md"), "# Plain\n\nNo front matter here.\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(temp_dir.path()).unwrap();
+    let articles = pipeline.load_articles().unwrap();
+    assert!(articles[0].has_front_matter);
+    assert!(!articles[1].has_front_matter);
+
+    let report = pipeline.validate(&articles).unwrap();
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].source_article, "plain");
+    assert_eq!(report.summary.orphaned_articles, 0);
+}
+
+#[test]
+fn test_link_graph_output_matches_frontend_format() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.md"), "---\ntitle: \"A\"\n---\n\n[[b]]\n").unwrap();
+    fs::write(temp_dir.path().join("b.md"), "---\ntitle: \"B\"\n---\n\n[[a]]\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(temp_dir.path()).unwrap();
+    let mut articles = pipeline.load_articles().unwrap();
+    ArticlePipeline::calculate_inbound_counts(&mut articles);
+    let link_graph = ArticlePipeline::build_link_graph(&articles);
+
+    let json = serde_json::to_string(&link_graph).unwrap();
+    let parsed: LinkGraphData = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.total_connections, 2);
+    assert_eq!(parsed.bidirectional_pairs, 1);
+    assert_eq!(parsed.graph["a"].outbound_count, 1);
+    assert_eq!(parsed.graph["a"].connections[0].link_count, 1);
+}
+
+#[test]
+fn test_articles_data_carries_schema_version() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.md"), "---\ntitle: \"A\"\n---\n\nBody\n").unwrap();
+
+    let pipeline = ArticlePipeline::new(temp_dir.path()).unwrap();
+    let articles = pipeline.load_articles().unwrap();
+    let json = serde_json::to_string(&ArticlePipeline::build_articles_data(&articles)).unwrap();
+
+    let header: SchemaHeader = serde_json::from_str(&json).unwrap();
+    assert_eq!(header.schema_version, SCHEMA_VERSION);
+    assert!(check_schema_version(header.schema_version).is_ok());
+
+    let parsed: ArticlesData = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.articles[0].metadata.importance, 3);
+}
+
+#[test]
+fn test_unversioned_data_is_rejected() {
+    let legacy = r#"{"articles": [], "generated_at": "2024-01-01T00:00:00Z", "total_count": 0, "home_articles": []}"#;
+
+    let header: SchemaHeader = serde_json::from_str(legacy).unwrap();
+    let mismatch = check_schema_version(header.schema_version).unwrap_err();
+    assert_eq!(mismatch.found, 0);
+    assert_eq!(mismatch.expected, SCHEMA_VERSION);
+}
+
+#[test]
+fn test_lightweight_article_summary_and_targets() {
+    let long_paragraph = "日本語の文章です。".repeat(40);
+    let article = ProcessedArticle {
+        slug: "long".to_string(),
+        title: "Long".to_string(),
+        content: format!("# Long\n\n{}\n\nSecond paragraph.\n", long_paragraph),
+        html: String::new(),
+        metadata: ArticleMetadata::default(),
+        has_front_matter: true,
+        date_sources: Default::default(),
+        file_path: "articles/long.md".to_string(),
+        outbound_links: vec![],
+        assets: vec![],
+        external_links: vec![],
+        inbound_count: 0,
+        processed_at: "2024-01-01T00:00:00Z".to_string(),
+    };
+
+    let lightweight = LightweightArticle::from(&article);
+    let summary = lightweight.summary.unwrap();
+    assert_eq!(summary.chars().count(), 200);
+    assert!(summary.ends_with("..."));
+    assert!(lightweight.outbound_targets.is_empty());
+
+    let empty = ProcessedArticle { content: "# Only a heading\n".to_string(), ..article };
+    assert_eq!(LightweightArticle::from(&empty).summary, None);
+}
@@ -0,0 +1,38 @@
+#![cfg(all(not(target_arch = "wasm32"), feature = "cli-tools"))]
+
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+#[test]
+fn test_fix_dry_run_writes_only_the_patch_to_stdout() {
+    let temp_dir = TempDir::new().unwrap();
+    let articles_dir = temp_dir.path().join("articles");
+    fs::create_dir_all(&articles_dir).unwrap();
+    fs::write(articles_dir.join("tokio-basics.md"), "---\ntitle: Tokio\n---\n# Tokio\n").unwrap();
+    let source = "---\ntitle: Intro\nhome_display: true\n---\n\nSee [[tokio-basic]] and [[zzz]].\n";
+    fs::write(articles_dir.join("intro.md"), source).unwrap();
+
+    for format in ["console", "json", "sarif"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_validate-links"))
+            .current_dir(temp_dir.path())
+            .args(["--fix", "--dry-run", "--format", format])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.starts_with("--- a/articles/intro.md\n+++ b/articles/intro.md\n@@ "), "{}", stdout);
+        assert!(stdout.contains("\n-See [[tokio-basic]] and [[zzz]].\n+See [[tokio-basics]] and [[zzz]].\n"), "{}", stdout);
+        for line in stdout.lines() {
+            assert!(
+                ["--- ", "+++ ", "@@ ", " ", "-", "+"].iter().any(|prefix| line.starts_with(prefix)),
+                "not part of a unified diff with --format {}: {:?}",
+                format,
+                line,
+            );
+        }
+        assert_eq!(fs::read_to_string(articles_dir.join("intro.md")).unwrap(), source);
+    }
+}